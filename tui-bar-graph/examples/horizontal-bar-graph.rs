@@ -0,0 +1,78 @@
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton,
+    MouseEventKind,
+};
+use crossterm::execute;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::text::Line;
+use ratatui::{DefaultTerminal, Frame};
+use tui_bar_graph::{BarGraph, BarGraphState, BarStyle, ColorMode, Orientation};
+
+const PROCESSES: [(&str, f64); 8] = [
+    ("cargo", 92.5),
+    ("rust-analyzer", 71.0),
+    ("firefox", 54.2),
+    ("code", 38.9),
+    ("slack", 21.4),
+    ("zsh", 9.7),
+    ("tmux", 4.1),
+    ("sshd", 0.8),
+];
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let terminal = ratatui::init();
+    execute!(std::io::stdout(), EnableMouseCapture)?;
+    let result = run(terminal);
+    execute!(std::io::stdout(), DisableMouseCapture)?;
+    ratatui::restore();
+    result
+}
+
+fn run(mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
+    let mut state = BarGraphState::default();
+    state.select_first();
+    loop {
+        terminal.draw(|frame| render(frame, &mut state))?;
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                KeyCode::Up => state.select_previous(),
+                KeyCode::Down => state.select_next(),
+                _ => break Ok(()),
+            },
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(index) = state.index_at((mouse.column, mouse.row)) {
+                    state.select(index);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn render(frame: &mut Frame, state: &mut BarGraphState) {
+    let area = frame.area().intersection(Rect {
+        height: PROCESSES.len() as u16 + 3,
+        ..frame.area()
+    });
+    let [graph_area, tooltip_area] =
+        Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+    // the gradient's domain is [0, 1], so scale the percentages to match
+    let data = PROCESSES.iter().map(|(_, cpu)| cpu / 100.0).collect();
+    let bar_graph = BarGraph::new(data)
+        .with_gradient(colorgrad::preset::turbo())
+        .with_color_mode(ColorMode::Solid)
+        .with_bar_style(BarStyle::Solid)
+        .with_orientation(Orientation::Horizontal)
+        .with_min(0.0)
+        .with_max(1.0)
+        .with_value_ticks(5)
+        .with_value_format(|value| format!("{:.0}%", value * 100.0))
+        .with_gridlines(3)
+        .with_category_labels(PROCESSES.iter().map(|(name, _)| *name));
+    frame.render_stateful_widget(bar_graph, graph_area, state);
+
+    if let Some((name, cpu)) = state.selected().and_then(|index| PROCESSES.get(index)) {
+        frame.render_widget(Line::from(format!("{name}: {cpu:.1}% CPU")), tooltip_area);
+    }
+}
@@ -0,0 +1,51 @@
+use crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+use rand::Rng;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Color;
+use ratatui::{DefaultTerminal, Frame};
+use tui_bar_graph::{BarGraph, BarStyle, Series, SeriesLayout};
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let terminal = ratatui::init();
+    let result = run(terminal);
+    ratatui::restore();
+    result
+}
+
+fn run(mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
+    loop {
+        terminal.draw(render)?;
+        if matches!(
+            event::read()?,
+            Event::Key(KeyEvent {
+                kind: KeyEventKind::Press,
+                ..
+            })
+        ) {
+            break Ok(());
+        }
+    }
+}
+
+fn render(frame: &mut Frame) {
+    let [legend_area, graph_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(frame.area());
+    let data_count = graph_area.width as usize * 2;
+    let mut rng = rand::rng();
+    let user = (0..data_count)
+        .map(|_| rng.random_range(0.0..0.6))
+        .collect();
+    let system = (0..data_count)
+        .map(|_| rng.random_range(0.0..0.4))
+        .collect();
+    let bar_graph = BarGraph::from_series([
+        Series::new("user", user).with_color(Color::Blue),
+        Series::new("system", system).with_color(Color::Magenta),
+    ])
+    .with_series_layout(SeriesLayout::Stacked)
+    .with_bar_style(BarStyle::Braille)
+    .with_max(1.0);
+    frame.render_widget(bar_graph.legend(), legend_area);
+    frame.render_widget(bar_graph, graph_area);
+}
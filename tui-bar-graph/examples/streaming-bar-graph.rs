@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+use rand::Rng;
+use ratatui::{DefaultTerminal, Frame};
+use tui_bar_graph::{BarGraph, BarGraphState, BarStyle, ColorMode};
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let terminal = ratatui::init();
+    let result = run(terminal);
+    ratatui::restore();
+    result
+}
+
+fn run(mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
+    let mut state = BarGraphState::with_capacity(1000).with_autoscale_window(500);
+    let mut rng = rand::rng();
+    let mut sample = 0.5_f64;
+    loop {
+        // a random walk looks more like a live metric than independent random samples
+        sample = (sample + rng.random_range(-0.1..0.1)).clamp(0.0, 1.0);
+        state.push(sample);
+        terminal.draw(|frame| render(frame, &mut state))?;
+        if event::poll(Duration::from_millis(50))?
+            && matches!(
+                event::read()?,
+                Event::Key(KeyEvent {
+                    kind: KeyEventKind::Press,
+                    ..
+                })
+            )
+        {
+            break Ok(());
+        }
+    }
+}
+
+fn render(frame: &mut Frame, state: &mut BarGraphState) {
+    let bar_graph = BarGraph::default()
+        .with_gradient(colorgrad::preset::viridis())
+        .with_color_mode(ColorMode::VerticalGradient)
+        .with_fade(0.7)
+        .with_bar_style(BarStyle::Braille);
+    frame.render_stateful_widget(bar_graph, frame.area(), state);
+}
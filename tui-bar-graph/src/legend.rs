@@ -0,0 +1,101 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Direction, Rect};
+use ratatui_core::style::{Color, Style};
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+/// A widget that names the series of a bar graph next to their colors.
+///
+/// Use [`BarGraph::legend`](crate::BarGraph::legend) to create a legend for the series of a bar
+/// graph, or [`Legend::new`] to build one from names and colors.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use tui_bar_graph::{BarGraph, Series};
+///
+/// # fn render(frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+/// let user = Series::new("user", vec![0.2, 0.4, 0.3]).with_color(Color::Blue);
+/// let system = Series::new("system", vec![0.1, 0.1, 0.2]).with_color(Color::Magenta);
+/// let bar_graph = BarGraph::from_series([user, system]);
+/// frame.render_widget(bar_graph.legend(), area);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Legend<'a> {
+    /// The name and color of each entry.
+    entries: Vec<(Line<'a>, Color)>,
+
+    /// The symbol drawn in the color of each entry.
+    symbol: &'a str,
+
+    /// The direction that the entries are laid out in.
+    direction: Direction,
+}
+
+impl<'a> Legend<'a> {
+    /// Creates a new legend with the given names and colors.
+    pub fn new<I, L>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (L, Color)>,
+        L: Into<Line<'a>>,
+    {
+        Self {
+            entries: entries
+                .into_iter()
+                .map(|(name, color)| (name.into(), color))
+                .collect(),
+            symbol: "■",
+            direction: Direction::Horizontal,
+        }
+    }
+
+    /// Sets the symbol drawn in the color of each entry.
+    ///
+    /// The default is `■`.
+    pub const fn with_symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the direction that the entries are laid out in.
+    ///
+    /// The default is `Direction::Horizontal`, which places the entries on a single line. Use
+    /// `Direction::Vertical` to place each entry on its own line.
+    pub const fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+}
+
+impl Widget for Legend<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Legend<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let (mut x, mut y) = (area.x, area.y);
+        for (name, color) in &self.entries {
+            if x >= area.right() || y >= area.bottom() {
+                break;
+            }
+            let width = area.right() - x;
+            let (symbol_end, _) =
+                buf.set_stringn(x, y, self.symbol, width as usize, Style::new().fg(*color));
+            let (name_end, _) = buf.set_line(
+                symbol_end.saturating_add(1).min(area.right()),
+                y,
+                name,
+                area.right().saturating_sub(symbol_end + 1),
+            );
+            match self.direction {
+                Direction::Horizontal => x = name_end.saturating_add(2),
+                Direction::Vertical => y += 1,
+            }
+        }
+    }
+}
@@ -82,34 +82,67 @@
 //! [Joshka]: https://github.com/joshka
 //! [tui-widgets]: https://crates.io/crates/tui-widgets
 
+mod legend;
+mod missing;
+mod resample;
+mod scale;
+mod series;
+mod state;
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
 use colorgrad::Gradient;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
-use ratatui_core::style::Color;
-use ratatui_core::widgets::Widget;
+use ratatui_core::style::{Color, Style};
+use ratatui_core::symbols::line;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::{StatefulWidget, Widget};
 use strum::{Display, EnumString};
 
-const BRAILLE_PATTERNS: [[&str; 5]; 5] = [
-    ["⠀", "⢀", "⢠", "⢰", "⢸"],
-    ["⡀", "⣀", "⣠", "⣰", "⣸"],
-    ["⡄", "⣄", "⣤", "⣴", "⣼"],
-    ["⡆", "⣆", "⣦", "⣶", "⣾"],
-    ["⡇", "⣇", "⣧", "⣷", "⣿"],
-];
+pub use crate::legend::Legend;
+pub use crate::missing::MissingValues;
+use crate::resample::PositionMap;
+pub use crate::resample::{Downsample, Upsample};
+pub use crate::scale::Scale;
+pub use crate::series::{Series, SeriesLayout};
+pub use crate::state::BarGraphState;
+use crate::state::BarLayout;
+
+/// The brightness of the background of a heatmap, relative to the color of the bar.
+const HEATMAP_BRIGHTNESS: f64 = 0.4;
 
-const OCTANT_PATTERNS: [[&str; 5]; 5] = [
-    ["⠀", "𜺠", "▗", "𜶖", "▐"],
-    ["𜺣", "▂", "𜷋", "𜷓", "𜷕"],
-    ["▖", "𜶻", "▄", "𜷡", "▟"],
-    ["𜵈", "𜶿", "𜷞", "▆", "𜷥"],
-    ["▌", "𜷀", "▙", "𜷤", "█"],
+/// Quadrant block characters indexed by a mask of the filled quadrants.
+///
+/// Bit 0 is the top left quadrant, bit 1 the top right, bit 2 the bottom left and bit 3 the bottom
+/// right.
+#[rustfmt::skip]
+const QUADRANT_SYMBOLS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
 ];
 
+/// Octant block characters indexed by a mask of the filled octants.
+///
+/// Bits are numbered left to right and then top to bottom, so bit 0 is the top left octant and
+/// bit 7 is the bottom right octant.
 #[rustfmt::skip]
-const QUADRANT_PATTERNS: [[&str; 3]; 3]= [
-    [" ", "▗", "▐"],
-    ["▖", "▄", "▟"],
-    ["▌", "▙", "█"],
+const OCTANT_SYMBOLS: [char; 256] = [
+    ' ', '𜺨', '𜺫', '🮂', '𜴀', '▘', '𜴁', '𜴂', '𜴃', '𜴄', '▝', '𜴅', '𜴆', '𜴇', '𜴈', '▀', '𜴉', '𜴊',
+    '𜴋', '𜴌', '🯦', '𜴍', '𜴎', '𜴏', '𜴐', '𜴑', '𜴒', '𜴓', '𜴔', '𜴕', '𜴖', '𜴗', '𜴘', '𜴙', '𜴚', '𜴛',
+    '𜴜', '𜴝', '𜴞', '𜴟', '🯧', '𜴠', '𜴡', '𜴢', '𜴣', '𜴤', '𜴥', '𜴦', '𜴧', '𜴨', '𜴩', '𜴪', '𜴫', '𜴬',
+    '𜴭', '𜴮', '𜴯', '𜴰', '𜴱', '𜴲', '𜴳', '𜴴', '𜴵', '🮅', '𜺣', '𜴶', '𜴷', '𜴸', '𜴹', '𜴺', '𜴻', '𜴼',
+    '𜴽', '𜴾', '𜴿', '𜵀', '𜵁', '𜵂', '𜵃', '𜵄', '▖', '𜵅', '𜵆', '𜵇', '𜵈', '▌', '𜵉', '𜵊', '𜵋', '𜵌',
+    '▞', '𜵍', '𜵎', '𜵏', '𜵐', '▛', '𜵑', '𜵒', '𜵓', '𜵔', '𜵕', '𜵖', '𜵗', '𜵘', '𜵙', '𜵚', '𜵛', '𜵜',
+    '𜵝', '𜵞', '𜵟', '𜵠', '𜵡', '𜵢', '𜵣', '𜵤', '𜵥', '𜵦', '𜵧', '𜵨', '𜵩', '𜵪', '𜵫', '𜵬', '𜵭', '𜵮',
+    '𜵯', '𜵰', '𜺠', '𜵱', '𜵲', '𜵳', '𜵴', '𜵵', '𜵶', '𜵷', '𜵸', '𜵹', '𜵺', '𜵻', '𜵼', '𜵽', '𜵾', '𜵿',
+    '𜶀', '𜶁', '𜶂', '𜶃', '𜶄', '𜶅', '𜶆', '𜶇', '𜶈', '𜶉', '𜶊', '𜶋', '𜶌', '𜶍', '𜶎', '𜶏', '▗', '𜶐',
+    '𜶑', '𜶒', '𜶓', '▚', '𜶔', '𜶕', '𜶖', '𜶗', '▐', '𜶘', '𜶙', '𜶚', '𜶛', '▜', '𜶜', '𜶝', '𜶞', '𜶟',
+    '𜶠', '𜶡', '𜶢', '𜶣', '𜶤', '𜶥', '𜶦', '𜶧', '𜶨', '𜶩', '𜶪', '𜶫', '▂', '𜶬', '𜶭', '𜶮', '𜶯', '𜶰',
+    '𜶱', '𜶲', '𜶳', '𜶴', '𜶵', '𜶶', '𜶷', '𜶸', '𜶹', '𜶺', '𜶻', '𜶼', '𜶽', '𜶾', '𜶿', '𜷀', '𜷁', '𜷂',
+    '𜷃', '𜷄', '𜷅', '𜷆', '𜷇', '𜷈', '𜷉', '𜷊', '𜷋', '𜷌', '𜷍', '𜷎', '𜷏', '𜷐', '𜷑', '𜷒', '𜷓', '𜷔',
+    '𜷕', '𜷖', '𜷗', '𜷘', '𜷙', '𜷚', '▄', '𜷛', '𜷜', '𜷝', '𜷞', '▙', '𜷟', '𜷠', '𜷡', '𜷢', '▟', '𜷣',
+    '▆', '𜷤', '𜷥', '█',
 ];
 
 /// A widget for displaying a bar graph.
@@ -132,8 +165,17 @@ const QUADRANT_PATTERNS: [[&str; 3]; 3]= [
 /// # }
 /// ```
 pub struct BarGraph<'g> {
-    /// The data to display as bars.
-    data: Vec<f64>,
+    /// The series of data to display as bars.
+    series: Vec<Series<'g>>,
+
+    /// How the bars of multiple series are arranged.
+    series_layout: SeriesLayout,
+
+    /// How values are combined when there are more values than fit in the graph.
+    downsample: Downsample,
+
+    /// How bars are placed when there are fewer values than fit in the graph.
+    upsample: Upsample,
 
     /// The maximum value to display.
     max: Option<f64>,
@@ -141,27 +183,121 @@ pub struct BarGraph<'g> {
     /// The minimum value to display.
     min: Option<f64>,
 
+    /// The value that bars grow from.
+    baseline: Option<f64>,
+
+    /// How values are mapped to positions along the value axis.
+    scale: Scale,
+
+    /// A custom mapping of values to positions, which takes precedence over the scale.
+    scale_fn: Option<Box<dyn Fn(f64) -> f64 + 'g>>,
+
     /// A gradient to use for coloring the bars.
     gradient: Option<Box<dyn Gradient + 'g>>,
 
+    /// The colors used for values at or above each threshold, sorted by threshold.
+    thresholds: Vec<(f64, Color)>,
+
+    /// Colors that override the color of the bars at the given indexes.
+    bar_colors: BTreeMap<usize, Color>,
+
     /// The direction of the gradient coloring.
     color_mode: ColorMode,
 
+    /// How much the colors of older bars are dimmed.
+    fade: f64,
+
+    /// Whether the background of each bar's column is colored.
+    heatmap: bool,
+
     /// The style of bar to render.
     bar_style: BarStyle,
+
+    /// The width of each bar, in bars of the bar style.
+    bar_width: usize,
+
+    /// The space between the bars at adjacent positions, in bars of the bar style.
+    bar_gap: usize,
+
+    /// The color of the selected bar.
+    selected_color: Color,
+
+    /// The direction that the bars grow in.
+    orientation: Orientation,
+
+    /// The number of tick labels to show along the value axis.
+    value_ticks: usize,
+
+    /// Formats the values shown in the tick labels.
+    value_format: Option<Box<dyn Fn(f64) -> String + 'g>>,
+
+    /// The labels to show along the category axis.
+    category_labels: Vec<Line<'g>>,
+
+    /// The annotations to show above the bars at the given indexes.
+    annotations: BTreeMap<usize, Line<'g>>,
+
+    /// How missing values are displayed.
+    missing_values: MissingValues,
+
+    /// The symbol drawn for missing values.
+    missing_marker: &'g str,
+
+    /// The number of gridlines to draw across the graph.
+    gridlines: usize,
+
+    /// The style of the axes and their labels.
+    axis_style: Style,
+
+    /// The style of the gridlines.
+    gridline_style: Style,
+}
+
+/// A single bar, or a segment of a stacked bar, positioned in dots.
+struct Bar {
+    /// The columns of dots covered by the bar along the category axis.
+    slots: Range<usize>,
+    /// The index of the value in the resampled data.
+    index: usize,
+    /// The dots covered by the bar along the value axis.
+    extent: Range<usize>,
+    /// The value that the bar represents.
+    value: f64,
+    /// The index of the series that the bar belongs to.
+    series: usize,
+}
+
+/// The areas that the parts of a bar graph are rendered in.
+struct GraphLayout {
+    /// The area that the bars are rendered in.
+    bars: Rect,
+    /// The area that the value axis and its tick labels are rendered in.
+    value_axis: Rect,
+    /// The area that the category labels are rendered in.
+    category_axis: Rect,
+    /// The area that the annotations are rendered in.
+    annotations: Rect,
 }
 
 /// The direction of the gradient coloring.
 ///
 /// - `Solid`: Each bar has a single color based on its value.
-/// - `Gradient`: Each bar is gradient-colored from bottom to top.
+/// - `VerticalGradient`: Each bar is gradient-colored from bottom to top.
+/// - `HorizontalGradient`: The bars are gradient-colored from left to right across the graph.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum ColorMode {
     /// Each bar has a single color based on its value.
     Solid,
     /// Each bar is gradient-colored from bottom to top.
+    ///
+    /// Horizontal bars are gradient-colored from left to right.
     #[default]
     VerticalGradient,
+    /// The bars are gradient-colored from left to right across the graph, so each bar has a
+    /// single color based on its position.
+    ///
+    /// The bars of a horizontal graph are gradient-colored from top to bottom.
+    HorizontalGradient,
 }
 
 /// The style of bar to render.
@@ -196,16 +332,128 @@ pub enum BarStyle {
     Octant,
 }
 
+/// The direction that the bars grow in.
+///
+/// - `Vertical`: Each value is a column, and bars grow up from the bottom of the graph.
+/// - `Horizontal`: Each value is a row, and bars grow right from the left of the graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Orientation {
+    /// Each value is a column, and bars grow up from the bottom of the graph.
+    #[default]
+    Vertical,
+    /// Each value is a row, and bars grow right from the left of the graph.
+    ///
+    /// The first value is rendered at the top of the graph, which makes this orientation useful
+    /// for ranked lists with a label next to each bar. Solid bars are rendered with left aligned
+    /// eighth blocks (`▏`, `▎`, `▍`, `▌`, `▋`, `▊`, `▉`, `█`).
+    Horizontal,
+}
+
+impl BarStyle {
+    /// The resolution of a single cell in the given orientation.
+    ///
+    /// The first value is the number of bars that share a cell, and the second value is the
+    /// number of dots along each bar within the cell.
+    const fn cell_resolution(self, orientation: Orientation) -> (usize, usize) {
+        match (self, orientation) {
+            (Self::Solid, Orientation::Vertical) => (1, 1),
+            (Self::Solid, Orientation::Horizontal) => (1, 8),
+            (Self::Quadrant, _) => (2, 2),
+            (Self::Braille | Self::Octant, Orientation::Vertical) => (2, 4),
+            (Self::Braille | Self::Octant, Orientation::Horizontal) => (4, 2),
+        }
+    }
+
+    /// The symbol for a cell with the given dots filled.
+    ///
+    /// The mask has bit `row * columns + column` set for each filled dot, counting rows from the
+    /// top of the cell.
+    fn symbol(self, orientation: Orientation, mask: u8) -> char {
+        match (self, orientation) {
+            (Self::Solid, Orientation::Vertical) => '█',
+            (Self::Solid, Orientation::Horizontal) => eighth_symbol(mask),
+            (Self::Quadrant, _) => QUADRANT_SYMBOLS[mask as usize],
+            (Self::Octant, _) => OCTANT_SYMBOLS[mask as usize],
+            (Self::Braille, _) => braille_symbol(mask),
+        }
+    }
+}
+
+/// Converts a mask of filled eighths of a cell (bit 0 is the leftmost) to a block character.
+///
+/// Bars that reach the left edge of the cell use left aligned blocks, and bars that reach only the
+/// right edge (e.g. bars growing left from a baseline) use right aligned blocks. There are no
+/// blocks for bars that reach neither edge, which happens when the baseline is inside the cell, so
+/// these are moved to the nearer edge, keeping their width.
+fn eighth_symbol(mask: u8) -> char {
+    const LEFT: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+    const RIGHT: [char; 8] = ['▕', '🮇', '🮈', '▐', '🮉', '🮊', '🮋', '█'];
+    let eighths = mask.count_ones() as usize;
+    // the leading zeros are the empty eighths on the right, and the trailing zeros on the left
+    if mask.leading_zeros() < mask.trailing_zeros() {
+        RIGHT[eighths - 1]
+    } else {
+        LEFT[eighths - 1]
+    }
+}
+
+/// Converts a mask of filled dots (laid out as for [`OCTANT_SYMBOLS`]) to a braille character.
+fn braille_symbol(mask: u8) -> char {
+    // braille numbers the dots down the left column and then down the right column, with the
+    // bottom row added later as dots 7 and 8
+    const DOTS: [u32; 8] = [0x01, 0x08, 0x02, 0x10, 0x04, 0x20, 0x40, 0x80];
+    let dots = DOTS
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| mask & (1 << bit) != 0)
+        .map(|(_, dot)| dot)
+        .sum::<u32>();
+    char::from_u32(0x2800 + dots).unwrap_or(' ')
+}
+
 impl<'g> BarGraph<'g> {
     /// Creates a new bar graph with the given data.
     pub fn new(data: Vec<f64>) -> Self {
+        Self::from_series([Series::new(Line::default(), data)])
+    }
+
+    /// Creates a new bar graph that displays multiple series of data.
+    ///
+    /// The series are grouped side by side at each position by default. Use
+    /// [`with_series_layout`](Self::with_series_layout) to stack them instead, and
+    /// [`legend`](Self::legend) to name them.
+    pub fn from_series(series: impl IntoIterator<Item = Series<'g>>) -> Self {
         Self {
-            data,
+            series: series.into_iter().collect(),
+            series_layout: SeriesLayout::default(),
+            downsample: Downsample::default(),
+            upsample: Upsample::default(),
             max: None,
             min: None,
+            baseline: None,
+            scale: Scale::default(),
+            scale_fn: None,
             gradient: None,
+            thresholds: Vec::new(),
+            bar_colors: BTreeMap::new(),
             color_mode: ColorMode::default(),
+            fade: 0.0,
+            heatmap: false,
             bar_style: BarStyle::default(),
+            bar_width: 1,
+            bar_gap: 0,
+            selected_color: Color::White,
+            orientation: Orientation::default(),
+            value_ticks: 0,
+            value_format: None,
+            category_labels: Vec::new(),
+            annotations: BTreeMap::new(),
+            missing_values: MissingValues::default(),
+            missing_marker: "×",
+            gridlines: 0,
+            axis_style: Style::new(),
+            gridline_style: Style::new().fg(Color::DarkGray),
         }
     }
 
@@ -214,11 +462,44 @@ impl<'g> BarGraph<'g> {
     /// See the [colorgrad] crate for information on creating gradients. Note that the default
     /// domain (range) of the gradient is [0, 1], so you may need to scale your data to fit this
     /// range, or modify the gradient's domain to fit your data.
+    ///
+    /// Series that have their own color or gradient are not colored with this gradient.
     pub fn with_gradient(mut self, gradient: impl Gradient + 'g) -> Self {
         self.gradient = Some(gradient.boxed());
         self
     }
 
+    /// Sets colors that are used for values at or above each threshold.
+    ///
+    /// Each color is used from its threshold up to the next one. Values below the lowest threshold
+    /// use the color of the lowest threshold. For example, `[(0.0, Color::Green), (0.7,
+    /// Color::Yellow), (0.9, Color::Red)]` colors values green below 0.7, yellow up to 0.9 and red
+    /// above.
+    ///
+    /// With `ColorMode::Solid`, each bar has the color for its value. With
+    /// `ColorMode::VerticalGradient`, each cell has the color for the value at its position, so the
+    /// bars are banded at the thresholds. The thresholds take precedence over the gradient of the
+    /// bar graph, but not over the color or gradient of a series.
+    pub fn with_thresholds<I>(mut self, thresholds: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, Color)>,
+    {
+        self.thresholds = thresholds.into_iter().collect();
+        self.thresholds.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self
+    }
+
+    /// Sets a color that overrides the color of the bar at the given index.
+    ///
+    /// This is useful for highlighting a selected or anomalous bar. The color is used for the bars
+    /// of every series at the index, and takes precedence over all other colors. When the data is
+    /// downsampled, a bar that covers several values uses the color of the first of them that has
+    /// one.
+    pub fn with_bar_color(mut self, index: usize, color: impl Into<Color>) -> Self {
+        self.bar_colors.insert(index, color.into());
+        self
+    }
+
     /// Sets the maximum value to display.
     ///
     /// Values greater than this will be clamped to this value. If `None`, the maximum value is
@@ -237,17 +518,79 @@ impl<'g> BarGraph<'g> {
         self
     }
 
+    /// Sets the baseline value that bars grow from.
+    ///
+    /// Bars for values above the baseline grow up from the baseline, and bars for values below it
+    /// grow down. This is useful for data that crosses zero, such as profit and loss. When the
+    /// minimum or maximum is calculated from the data, the range is extended to include the
+    /// baseline. If `None` (the default), bars grow up from the bottom of the graph.
+    pub fn with_baseline(mut self, baseline: impl Into<Option<f64>>) -> Self {
+        self.baseline = baseline.into();
+        self
+    }
+
+    /// Sets how values are mapped to positions along the value axis.
+    ///
+    /// The default is `Scale::Linear`. A logarithmic scale is useful for heavy-tailed data, such
+    /// as request latencies or file sizes, where a few large values would otherwise flatten the
+    /// rest of the bars. The scale is used for the length of the bars, the position of the tick
+    /// labels, and the color of the bars when using `ColorMode::Solid`.
+    ///
+    /// - `Linear`: Positions are proportional to the values.
+    /// - `Log10`: Positions are proportional to the base 10 logarithm of the values.
+    /// - `Symlog`: Like `Log10`, but linear near zero so that zero and negative values can be
+    ///   shown.
+    /// - `Sqrt`: Positions are proportional to the square root of the values.
+    pub fn with_scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self.scale_fn = None;
+        self
+    }
+
+    /// Sets a custom function that maps values to positions along the value axis.
+    ///
+    /// The function must increase with the value. Only the differences between its results
+    /// matter, so for example `|value| value.ln()` gives the same graph as `Scale::Log10`.
+    pub fn with_scale_fn(mut self, scale: impl Fn(f64) -> f64 + 'g) -> Self {
+        self.scale_fn = Some(Box::new(scale));
+        self
+    }
+
     /// Sets the color mode for the bars.
     ///
     /// The default is `ColorMode::VerticalGradient`.
     ///
     /// - `Solid`: Each bar has a single color based on its value.
-    /// - `Gradient`: Each bar is gradient-colored from bottom to top.
+    /// - `VerticalGradient`: Each bar is gradient-colored from bottom to top.
+    /// - `HorizontalGradient`: The bars are gradient-colored from left to right across the graph.
     pub const fn with_color_mode(mut self, color: ColorMode) -> Self {
         self.color_mode = color;
         self
     }
 
+    /// Sets how much the colors of older bars are dimmed.
+    ///
+    /// The first bar is treated as the oldest, as it is when streaming samples with a
+    /// [`BarGraphState`], and the last bar as the newest. The newest bar is shown at full
+    /// brightness, and the brightness falls linearly to `1.0 - amount` for the oldest bar. The
+    /// amount is clamped to `0.0..=1.0`, and the default is 0, which doesn't dim any bars.
+    ///
+    /// Only RGB colors, such as the colors from a gradient, can be dimmed.
+    pub const fn with_fade(mut self, amount: f64) -> Self {
+        self.fade = amount.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets whether the background of the graph is colored like a heatmap.
+    ///
+    /// When enabled, every cell in the column of a bar (or the row of a horizontal bar) has a
+    /// background that is a darker shade of the color for the bar's value, whatever the color
+    /// mode. The default is `false`.
+    pub const fn with_heatmap(mut self, heatmap: bool) -> Self {
+        self.heatmap = heatmap;
+        self
+    }
+
     /// Sets the style of the bars.
     ///
     /// The default is `BarStyle::Braille`.
@@ -265,219 +608,1118 @@ impl<'g> BarGraph<'g> {
         self
     }
 
-    /// Renders the graph using solid blocks (█).
-    fn render_solid(&self, area: Rect, buf: &mut Buffer, min: f64, max: f64) {
-        let range = max - min;
-        for (&value, column) in self.data.iter().zip(area.columns()) {
-            let normalized = (value - min) / range;
-            let column_height = (normalized * area.height as f64).ceil() as usize;
-            for (i, row) in column.rows().rev().enumerate().take(column_height) {
-                let color = self.color_for(area, min, range, value, i);
-                buf[row].set_symbol("█").set_fg(color);
-            }
-        }
+    /// Sets the width of each bar.
+    ///
+    /// The width is measured in the bars of the bar style, so a width of 2 is a single cell wide
+    /// with `BarStyle::Solid` and half a cell wide with `BarStyle::Braille`. The default is 1, and
+    /// widths less than 1 are treated as 1.
+    pub const fn with_bar_width(mut self, width: usize) -> Self {
+        self.bar_width = if width == 0 { 1 } else { width };
+        self
     }
 
-    /// Renders the graph using braille characters.
-    fn render_braille(&self, area: Rect, buf: &mut Buffer, min: f64, max: f64) {
-        self.render_pattern(area, buf, min, max, 4, &BRAILLE_PATTERNS);
+    /// Sets the space between the bars at adjacent positions.
+    ///
+    /// The gap is measured in the bars of the bar style, like the
+    /// [bar width](Self::with_bar_width). The bars of grouped series at the same position are
+    /// placed next to each other, with the gap between the groups. The default is 0.
+    pub const fn with_bar_gap(mut self, gap: usize) -> Self {
+        self.bar_gap = gap;
+        self
     }
 
-    /// Renders the graph using octant blocks.
-    fn render_octant(&self, area: Rect, buf: &mut Buffer, min: f64, max: f64) {
-        self.render_pattern(area, buf, min, max, 4, &OCTANT_PATTERNS);
+    /// Sets the color of the selected bar.
+    ///
+    /// The bar is selected using a [`BarGraphState`] when the graph is rendered as a
+    /// [`StatefulWidget`]. The default is `Color::White`.
+    pub fn with_selected_color(mut self, color: impl Into<Color>) -> Self {
+        self.selected_color = color.into();
+        self
     }
 
-    /// Renders the graph using quadrant blocks.
-    fn render_quadrant(&self, area: Rect, buf: &mut Buffer, min: f64, max: f64) {
-        self.render_pattern(area, buf, min, max, 2, &QUADRANT_PATTERNS);
+    /// Sets the orientation of the bars.
+    ///
+    /// The default is `Orientation::Vertical`.
+    ///
+    /// - `Vertical`: Each value is a column, and bars grow up from the bottom of the graph.
+    /// - `Horizontal`: Each value is a row, and bars grow right from the left of the graph.
+    pub const fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
     }
 
-    /// Common rendering logic for pattern-based bar styles.
-    fn render_pattern<const N: usize, const M: usize>(
-        &self,
-        area: Rect,
-        buf: &mut Buffer,
-        min: f64,
-        max: f64,
-        dots_per_row: usize,
-        patterns: &[[&str; N]; M],
-    ) {
-        let range = max - min;
-        let row_count = area.height;
-        let total_dots = row_count as usize * dots_per_row;
-
-        for (chunk, column) in self
-            .data
-            .chunks(2)
-            .zip(area.columns())
-            .take(area.width as usize)
-        {
-            let left_value = chunk[0];
-            let right_value = chunk.get(1).copied().unwrap_or(min);
-
-            let left_normalized = (left_value - min) / range;
-            let right_normalized = (right_value - min) / range;
-
-            let left_total_dots = (left_normalized * total_dots as f64).round() as usize;
-            let right_total_dots = (right_normalized * total_dots as f64).round() as usize;
+    /// Sets how the bars of multiple series are arranged.
+    ///
+    /// The default is `SeriesLayout::Grouped`.
+    ///
+    /// - `Grouped`: The bars for each series are placed side by side.
+    /// - `Stacked`: The bars for each series are stacked on top of each other.
+    ///
+    /// Each cell of the graph has a single color, so cells that are shared by bars from
+    /// different series are colored for the series that fills most of the cell.
+    pub const fn with_series_layout(mut self, layout: SeriesLayout) -> Self {
+        self.series_layout = layout;
+        self
+    }
 
-            let column_height = (left_total_dots.max(right_total_dots) as f64 / dots_per_row as f64)
-                .ceil() as usize;
+    /// Creates a legend that names each series next to its color.
+    ///
+    /// Series colored with a gradient are shown using the color at the middle of the gradient.
+    pub fn legend(&self) -> Legend<'_> {
+        Legend::new(self.series.iter().map(|series| {
+            let color = series.color.unwrap_or_else(|| {
+                series.gradient.as_ref().or(self.gradient.as_ref()).map_or(
+                    Color::Reset,
+                    |gradient| {
+                        let (start, end) = gradient.domain();
+                        gradient_color(gradient.as_ref(), f64::from(start + end) / 2.0)
+                    },
+                )
+            });
+            (series.name.clone(), color)
+        }))
+    }
 
-            for (row_index, row) in column.rows().rev().enumerate().take(column_height) {
-                let value = f64::midpoint(left_value, right_value);
-                let color = self.color_for(area, min, max, value, row_index);
+    /// Sets how values are combined when there are more values than fit in the graph.
+    ///
+    /// The default is `Downsample::Truncate`, which doesn't display the values that don't fit.
+    /// The other modes spread the values evenly across the graph, with each bar showing the mean,
+    /// max, min, last value or sum of the values that it covers.
+    pub const fn with_downsample(mut self, downsample: Downsample) -> Self {
+        self.downsample = downsample;
+        self
+    }
 
-                let dots_below = row_index * dots_per_row;
-                let left_dots = left_total_dots.saturating_sub(dots_below).min(dots_per_row);
-                let right_dots = right_total_dots
-                    .saturating_sub(dots_below)
-                    .min(dots_per_row);
+    /// Sets how bars are placed when there are fewer values than fit in the graph.
+    ///
+    /// The default is `Upsample::Left`.
+    ///
+    /// - `Left`: The bars are placed at the start of the graph.
+    /// - `Center`: The bars are placed in the middle of the graph.
+    /// - `Stretch`: Each value is repeated to fill the graph.
+    pub const fn with_upsample(mut self, upsample: Upsample) -> Self {
+        self.upsample = upsample;
+        self
+    }
 
-                let symbol = patterns[left_dots][right_dots];
-                buf[row].set_symbol(symbol).set_fg(color);
-            }
-        }
+    /// Sets the number of tick labels to show along the value axis.
+    ///
+    /// The ticks are evenly spaced from the minimum to the maximum value, so a count of 2 labels
+    /// just the ends of the axis. The value axis is drawn on the left of a vertical graph and along
+    /// the bottom of a horizontal graph, and the bars shrink to make room for it. The default is 0,
+    /// which hides the value axis.
+    pub const fn with_value_ticks(mut self, count: usize) -> Self {
+        self.value_ticks = count;
+        self
     }
 
-    fn color_for(&self, area: Rect, min: f64, max: f64, value: f64, row: usize) -> Color {
-        let color_value = match self.color_mode {
-            ColorMode::Solid => value,
-            ColorMode::VerticalGradient => {
-                (row as f64 / area.height as f64).mul_add(max - min, min)
-            }
-        };
-        self.gradient
-            .as_ref()
-            .map(|gradient| {
-                let color = gradient.at(color_value as f32);
-                let rgba = color.to_rgba8();
-                // TODO this can be changed to .into() in ratatui 0.30
-                Color::Rgb(rgba[0], rgba[1], rgba[2])
-            })
-            .unwrap_or(Color::Reset)
+    /// Sets the function used to format the values in the tick labels.
+    ///
+    /// By default, values are shown with enough decimal places to tell the ticks apart.
+    pub fn with_value_format(mut self, format: impl Fn(f64) -> String + 'g) -> Self {
+        self.value_format = Some(Box::new(format));
+        self
     }
-}
 
-impl Widget for BarGraph<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        // f64 doesn't impl Ord because NaN != NaN, so we use fold instead of iter::max/min
-        let min = self
-            .min
-            .unwrap_or_else(|| self.data.iter().copied().fold(f64::INFINITY, f64::min));
-        let max = self
-            .max
-            .unwrap_or_else(|| self.data.iter().copied().fold(f64::NEG_INFINITY, f64::max));
-        let max = max.max(min + f64::EPSILON); // avoid division by zero if min == max
-        match self.bar_style {
-            BarStyle::Braille => self.render_braille(area, buf, min, max),
-            BarStyle::Solid => self.render_solid(area, buf, min, max),
-            BarStyle::Quadrant => self.render_quadrant(area, buf, min, max),
-            BarStyle::Octant => self.render_octant(area, buf, min, max),
-        }
+    /// Sets the labels to show along the category axis.
+    ///
+    /// Each label is shown next to the bar for the value at the same index. The labels are drawn
+    /// along the bottom of a vertical graph and on the left of a horizontal graph, and the bars
+    /// shrink to make room for them. Labels that would overlap a previous label are skipped.
+    pub fn with_category_labels<I, L>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Line<'g>>,
+    {
+        self.category_labels = labels.into_iter().map(Into::into).collect();
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Adds an annotation to show above the bar for the value at the given index.
+    ///
+    /// This is useful for marking events, such as deploys, on a graph of metrics. The annotations
+    /// are drawn in a row above a vertical graph and in a column to the right of a horizontal
+    /// graph, and the bars shrink to make room for them. Annotations that would overlap a previous
+    /// annotation are skipped.
+    pub fn with_annotation(mut self, index: usize, label: impl Into<Line<'g>>) -> Self {
+        self.annotations.insert(index, label.into());
+        self
+    }
 
-    #[test]
-    fn with_gradient() {
-        let data = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
-        // check that we can use either a gradient or a boxed gradient
-        let _graph = BarGraph::new(data.clone()).with_gradient(colorgrad::preset::turbo());
-        let _graph = BarGraph::new(data).with_gradient(colorgrad::preset::turbo().boxed());
+    /// Sets how missing values are displayed.
+    ///
+    /// Values that are not finite, such as `f64::NAN`, are treated as missing. The default is
+    /// `MissingValues::Skip`.
+    ///
+    /// - `Skip`: No bar is drawn for a missing value.
+    /// - `Marker`: A marker is drawn at the base of the bar for a missing value.
+    /// - `Interpolate`: Missing values are replaced by interpolating between their neighbors.
+    pub const fn with_missing_values(mut self, missing_values: MissingValues) -> Self {
+        self.missing_values = missing_values;
+        self
     }
 
-    #[test]
-    fn braille() {
-        let data = (0..=40).map(|i| i as f64 * 0.125).collect();
-        let bar_graph = BarGraph::new(data);
+    /// Sets the symbol drawn for missing values when using `MissingValues::Marker`.
+    ///
+    /// The marker is drawn in the axis style. The default is `×`.
+    pub const fn with_missing_marker(mut self, marker: &'g str) -> Self {
+        self.missing_marker = marker;
+        self
+    }
 
-        let mut buf = Buffer::empty(Rect::new(0, 0, 21, 10));
-        bar_graph.render(buf.area, &mut buf);
+    /// Sets the number of gridlines to draw across the graph.
+    ///
+    /// The gridlines are evenly spaced between the minimum and maximum value, so using two fewer
+    /// gridlines than value ticks lines them up with the inner ticks. The default is 0.
+    pub const fn with_gridlines(mut self, count: usize) -> Self {
+        self.gridlines = count;
+        self
+    }
 
-        assert_eq!(
-            buf,
-            Buffer::with_lines(vec![
-                "                  ⢀⣴⡇",
-                "                ⢀⣴⣿⣿⡇",
-                "              ⢀⣴⣿⣿⣿⣿⡇",
-                "            ⢀⣴⣿⣿⣿⣿⣿⣿⡇",
-                "          ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⡇",
-                "        ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
-                "      ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
-                "    ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
-                "  ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
-                "⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
-            ])
-        );
+    /// Sets the style of the axes and their labels.
+    pub fn with_axis_style(mut self, style: impl Into<Style>) -> Self {
+        self.axis_style = style.into();
+        self
     }
 
-    #[test]
-    fn solid() {
-        let data = vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5, 5.0];
-        let bar_graph = BarGraph::new(data).with_bar_style(BarStyle::Solid);
+    /// Sets the style of the gridlines.
+    ///
+    /// The default is a dark gray foreground.
+    pub fn with_gridline_style(mut self, style: impl Into<Style>) -> Self {
+        self.gridline_style = style.into();
+        self
+    }
 
-        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 10));
-        bar_graph.render(buf.area, &mut buf);
+    /// The tick labels for the value axis, paired with their position along the axis as a
+    /// fraction of its length.
+    fn tick_labels(&self, min: f64, max: f64) -> Vec<(f64, String)> {
+        (0..self.value_ticks)
+            .map(|tick| {
+                let fraction = if self.value_ticks > 1 {
+                    tick as f64 / (self.value_ticks - 1) as f64
+                } else {
+                    0.0
+                };
+                let value = self.unscaled(fraction, min, max);
+                let label = self
+                    .value_format
+                    .as_ref()
+                    .map_or_else(|| format_value(value, max - min), |format| format(value));
+                (fraction, label)
+            })
+            .collect()
+    }
 
-        assert_eq!(
-            buf,
-            Buffer::with_lines(vec![
-                "          █",
-                "         ██",
-                "        ███",
-                "       ████",
-                "      █████",
-                "     ██████",
-                "    ███████",
-                "   ████████",
-                "  █████████",
-                " ██████████",
-            ])
-        );
+    /// Splits the area into the areas for the bars and the axes.
+    fn layout(&self, area: Rect, tick_labels: &[(f64, String)]) -> GraphLayout {
+        let tick_width = tick_labels
+            .iter()
+            .map(|(_, label)| Line::from(label.as_str()).width())
+            .max()
+            .unwrap_or_default() as u16;
+        let category_width = self
+            .category_labels
+            .iter()
+            .map(Line::width)
+            .max()
+            .unwrap_or_default() as u16;
+        let annotation_width = self
+            .annotations
+            .values()
+            .map(Line::width)
+            .max()
+            .unwrap_or_default() as u16;
+        let has_ticks = !tick_labels.is_empty();
+        let has_categories = !self.category_labels.is_empty();
+        let has_annotations = !self.annotations.is_empty();
+        match self.orientation {
+            Orientation::Vertical => {
+                // the tick labels are followed by a column for the axis line
+                let left = if has_ticks { tick_width + 1 } else { 0 }.min(area.width);
+                let top = u16::from(has_annotations).min(area.height);
+                let bottom = u16::from(has_categories).min(area.height - top);
+                let bars = Rect {
+                    x: area.x + left,
+                    y: area.y + top,
+                    width: area.width - left,
+                    height: area.height - top - bottom,
+                };
+                GraphLayout {
+                    bars,
+                    value_axis: Rect {
+                        x: area.x,
+                        width: left,
+                        ..bars
+                    },
+                    category_axis: Rect {
+                        y: bars.bottom(),
+                        height: bottom,
+                        ..bars
+                    },
+                    annotations: Rect {
+                        y: area.y,
+                        height: top,
+                        ..bars
+                    },
+                }
+            }
+            Orientation::Horizontal => {
+                // the category labels are separated from the bars by a blank column, and the tick
+                // labels are drawn below a row for the axis line
+                let left = if has_categories {
+                    category_width + 1
+                } else {
+                    0
+                }
+                .min(area.width);
+                // the annotations are separated from the bars by a blank column
+                let right = if has_annotations {
+                    annotation_width + 1
+                } else {
+                    0
+                }
+                .min(area.width - left);
+                let bottom = if has_ticks { 2 } else { 0 }.min(area.height);
+                let bars = Rect {
+                    x: area.x + left,
+                    width: area.width - left - right,
+                    height: area.height - bottom,
+                    ..area
+                };
+                GraphLayout {
+                    bars,
+                    value_axis: Rect {
+                        y: bars.bottom(),
+                        height: bottom,
+                        ..bars
+                    },
+                    category_axis: Rect {
+                        x: area.x,
+                        width: left.saturating_sub(1),
+                        ..bars
+                    },
+                    annotations: Rect {
+                        x: (bars.right() + 1).min(area.right()),
+                        width: right.saturating_sub(1),
+                        ..bars
+                    },
+                }
+            }
+        }
     }
 
-    #[test]
-    fn quadrant() {
-        let data = vec![
-            0.0, 0.25, 0.5, 0.75, 1.0, 1.25, 1.5, 1.75, 2.0, 2.25, 2.5, 2.75, 3.0, 3.25, 3.5, 3.75,
-            4.0, 4.25, 4.5, 4.75, 5.0,
-        ];
-        let bar_graph = BarGraph::new(data).with_bar_style(BarStyle::Quadrant);
+    /// Renders the gridlines behind the bars.
+    fn render_gridlines(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        for gridline in 1..=self.gridlines {
+            let fraction = gridline as f64 / (self.gridlines + 1) as f64;
+            match self.orientation {
+                Orientation::Vertical => {
+                    let y = area.bottom() - 1 - axis_offset(fraction, area.height);
+                    for x in area.left()..area.right() {
+                        buf[(x, y)]
+                            .set_symbol(line::LIGHT_TRIPLE_DASH_HORIZONTAL)
+                            .set_style(self.gridline_style);
+                    }
+                }
+                Orientation::Horizontal => {
+                    let x = area.x + axis_offset(fraction, area.width);
+                    for y in area.top()..area.bottom() {
+                        buf[(x, y)]
+                            .set_symbol(line::LIGHT_TRIPLE_DASH_VERTICAL)
+                            .set_style(self.gridline_style);
+                    }
+                }
+            }
+        }
+    }
 
-        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 10));
-        bar_graph.render(buf.area, &mut buf);
+    /// Renders the value axis line with its tick marks and labels.
+    fn render_value_axis(&self, area: Rect, buf: &mut Buffer, tick_labels: &[(f64, String)]) {
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.axis_style);
+        match self.orientation {
+            Orientation::Vertical => {
+                let x = area.right() - 1;
+                for y in area.top()..area.bottom() {
+                    buf[(x, y)].set_symbol(line::VERTICAL);
+                }
+                for (fraction, label) in tick_labels {
+                    let y = area.bottom() - 1 - axis_offset(*fraction, area.height);
+                    buf[(x, y)].set_symbol(line::VERTICAL_LEFT);
+                    let width = Line::from(label.as_str()).width() as u16;
+                    let label_x = x.saturating_sub(width).max(area.x);
+                    buf.set_stringn(label_x, y, label, (x - label_x) as usize, Style::new());
+                }
+            }
+            Orientation::Horizontal => {
+                let y = area.y;
+                for x in area.left()..area.right() {
+                    buf[(x, y)].set_symbol(line::HORIZONTAL);
+                }
+                if area.height < 2 {
+                    return;
+                }
+                let mut next_label_x = area.x;
+                for (fraction, label) in tick_labels {
+                    let x = area.x + axis_offset(*fraction, area.width);
+                    buf[(x, y)].set_symbol(line::HORIZONTAL_DOWN);
+                    // center the label on its tick, but keep it within the axis
+                    let width = Line::from(label.as_str()).width() as u16;
+                    let label_x = x
+                        .saturating_sub(width / 2)
+                        .min(area.right().saturating_sub(width))
+                        .max(area.x);
+                    if label_x < next_label_x {
+                        continue;
+                    }
+                    let max_width = (area.right() - label_x) as usize;
+                    buf.set_stringn(label_x, y + 1, label, max_width, Style::new());
+                    next_label_x = label_x + width + 1;
+                }
+            }
+        }
+    }
 
-        assert_eq!(
+    /// Renders the category labels next to the bars that they label.
+    fn render_category_axis(&self, area: Rect, buf: &mut Buffer, positions: &PositionMap) {
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.axis_style);
+        self.render_labels(
+            area,
             buf,
-            Buffer::with_lines(vec![
-                "         ▗▌",
-                "        ▗█▌",
-                "       ▗██▌",
-                "      ▗███▌",
-                "     ▗████▌",
-                "    ▗█████▌",
-                "   ▗██████▌",
-                "  ▗███████▌",
-                " ▗████████▌",
-                "▗█████████▌",
-            ])
+            positions,
+            self.category_labels.iter().enumerate(),
         );
     }
 
-    #[test]
-    fn octant() {
-        let data = (0..=40).map(|i| i as f64 * 0.125).collect();
-        let bar_graph = BarGraph::new(data).with_bar_style(BarStyle::Octant);
-
-        let mut buf = Buffer::empty(Rect::new(0, 0, 21, 10));
-        bar_graph.render(buf.area, &mut buf);
+    /// Renders the annotations next to the ends of the bars that they belong to.
+    fn render_annotations(&self, area: Rect, buf: &mut Buffer, positions: &PositionMap) {
+        if area.is_empty() {
+            return;
+        }
+        let annotations = self
+            .annotations
+            .iter()
+            .map(|(&index, label)| (index, label));
+        self.render_labels(area, buf, positions, annotations);
+    }
 
-        assert_eq!(
+    /// Renders labels next to the bars for the values at their indexes, skipping labels that
+    /// would overlap a previous label.
+    fn render_labels<'a>(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        positions: &PositionMap,
+        labels: impl Iterator<Item = (usize, &'a Line<'a>)>,
+    ) {
+        let (bars_per_cell, _) = self.bar_style.cell_resolution(self.orientation);
+        let mut next_position = 0;
+        for (index, label) in labels {
+            let Some(position) = positions.position(index) else {
+                continue;
+            };
+            let position = (position * self.slots_per_position() / bars_per_cell) as u16;
+            if position < next_position {
+                continue;
+            }
+            match self.orientation {
+                Orientation::Vertical => {
+                    if position >= area.width {
+                        break;
+                    }
+                    let x = area.x + position;
+                    let (end, _) = buf.set_line(x, area.y, label, area.right() - x);
+                    next_position = end - area.x + 1;
+                }
+                Orientation::Horizontal => {
+                    if position >= area.height {
+                        break;
+                    }
+                    buf.set_line(area.x, area.y + position, label, area.width);
+                    next_position = position + 1;
+                }
+            }
+        }
+    }
+
+    /// Renders a marker at the base of each position where every value is missing.
+    fn render_missing_markers(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        data: &[Vec<f64>],
+        positions: &PositionMap,
+        min: f64,
+        max: f64,
+    ) {
+        if self.missing_values != MissingValues::Marker || area.is_empty() {
+            return;
+        }
+        let (bars_per_cell, dots_per_cell) = self.bar_style.cell_resolution(self.orientation);
+        let (category_cells, value_cells) = match self.orientation {
+            Orientation::Vertical => (area.width, area.height),
+            Orientation::Horizontal => (area.height, area.width),
+        };
+        let total_dots = value_cells as usize * dots_per_cell;
+        let base = self.dot_position(self.baseline.unwrap_or(min), min, max, total_dots);
+        let cell = ((base as usize / dots_per_cell) as u16).min(value_cells - 1);
+        for index in 0..longest(data) {
+            let missing = data
+                .iter()
+                .filter_map(|series| series.get(index))
+                .all(|value| !value.is_finite());
+            if !missing {
+                continue;
+            }
+            let slot = (positions.offset() + index) * self.slots_per_position();
+            let category = (slot / bars_per_cell) as u16;
+            if category >= category_cells {
+                break;
+            }
+            let (x, y) = match self.orientation {
+                Orientation::Vertical => (area.x + category, area.bottom() - 1 - cell),
+                Orientation::Horizontal => (area.x + cell, area.y + category),
+            };
+            buf.set_stringn(x, y, self.missing_marker, 1, self.axis_style);
+        }
+    }
+
+    /// The number of columns of dots used for each position in the data, including the gap.
+    fn slots_per_position(&self) -> usize {
+        let bars = match self.series_layout {
+            SeriesLayout::Grouped => self.series.len().max(1),
+            SeriesLayout::Stacked => 1,
+        };
+        bars * self.bar_width + self.bar_gap
+    }
+
+    /// The minimum and maximum values of the value axis.
+    fn value_range(&self, data: &[Vec<f64>]) -> (f64, f64) {
+        let (data_min, data_max) = self.data_range(data);
+        let mut min = self.min.unwrap_or(data_min);
+        let max = self.max.unwrap_or(data_max);
+        if self.scale == Scale::Log10 && self.scale_fn.is_none() && self.min.is_none() && min <= 0.0
+        {
+            // the logarithm of zero is -infinity, so start from the smallest positive value
+            let positive_min = data
+                .iter()
+                .flatten()
+                .copied()
+                .filter(|value| *value > 0.0)
+                .fold(f64::INFINITY, f64::min);
+            if positive_min.is_finite() {
+                min = positive_min;
+            }
+        }
+        (min, max.max(min + f64::EPSILON)) // avoid division by zero if min == max
+    }
+
+    /// Maps a value to its position on the scale.
+    fn scaled(&self, value: f64) -> f64 {
+        self.scale_fn
+            .as_ref()
+            .map_or_else(|| self.scale.apply(value), |scale| scale(value))
+    }
+
+    /// The position of a value as a fraction of the way from the minimum to the maximum.
+    ///
+    /// The fraction is not clamped, so values outside the range give fractions outside `0..=1`.
+    /// Values that can't be placed on the scale, such as negative values on a logarithmic scale,
+    /// are placed at the minimum.
+    fn fraction(&self, value: f64, min: f64, max: f64) -> f64 {
+        let start = self.scaled(min);
+        let fraction = (self.scaled(value) - start) / (self.scaled(max) - start);
+        if fraction.is_nan() {
+            0.0
+        } else {
+            fraction
+        }
+    }
+
+    /// The value at the given fraction of the way from the minimum to the maximum.
+    ///
+    /// This is the inverse of [`fraction`](Self::fraction). Scales other than `Linear` are
+    /// inverted by bisection, since a custom scale has no known inverse.
+    fn unscaled(&self, fraction: f64, min: f64, max: f64) -> f64 {
+        if self.scale == Scale::Linear && self.scale_fn.is_none() {
+            return fraction.mul_add(max - min, min);
+        }
+        let (mut low, mut high) = (min, max);
+        for _ in 0..64 {
+            let mid = low.midpoint(high);
+            if self.fraction(mid, min, max) < fraction {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low.midpoint(high)
+    }
+
+    /// The smallest and largest values reached by the bars.
+    ///
+    /// This is used for the minimum and maximum when they are not set explicitly. When there are
+    /// no values, the range is 0 to 1 so that the value axis still has finite labels.
+    fn data_range(&self, data: &[Vec<f64>]) -> (f64, f64) {
+        // f64 doesn't impl Ord because NaN != NaN, so we use fold instead of iter::max/min
+        let (min, max) = match self.series_layout {
+            SeriesLayout::Grouped => data
+                .iter()
+                .flatten()
+                .copied()
+                .filter(|value| value.is_finite())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                }),
+            SeriesLayout::Stacked => {
+                let base = self.baseline.unwrap_or(0.0);
+                (0..longest(data))
+                    .map(|index| {
+                        data.iter()
+                            .filter_map(|series| series.get(index))
+                            .filter(|value| value.is_finite())
+                            .fold((base, base), |(below, above), &value| {
+                                if value < 0.0 {
+                                    (below + value, above)
+                                } else {
+                                    (below, above + value)
+                                }
+                            })
+                    })
+                    .fold((base, base), |(min, max), (below, above)| {
+                        (min.min(below), max.max(above))
+                    })
+            }
+        };
+        let (min, max) = if min > max { (0.0, 1.0) } else { (min, max) };
+        match self.baseline {
+            Some(baseline) => (min.min(baseline), max.max(baseline)),
+            None => (min, max),
+        }
+    }
+
+    /// The number of positions along the category axis that fit in the area of the bars.
+    fn available_positions(&self, bars: Rect) -> usize {
+        let (bars_per_cell, _) = self.bar_style.cell_resolution(self.orientation);
+        let category_cells = match self.orientation {
+            Orientation::Vertical => bars.width,
+            Orientation::Horizontal => bars.height,
+        };
+        // the last position doesn't need a gap after it
+        (category_cells as usize * bars_per_cell + self.bar_gap) / self.slots_per_position()
+    }
+
+    /// Fits the data of each series to the positions available in the area of the bars.
+    fn resample(&self, area: Rect, data: &[Vec<f64>]) -> (PositionMap, Vec<Vec<f64>>) {
+        let positions = PositionMap::new(
+            longest(data),
+            self.available_positions(area),
+            self.downsample,
+            self.upsample,
+        );
+        let data = positions.resample(data);
+        (positions, data)
+    }
+
+    /// Positions the bars of every series, or the segments of the stacked bars, in dots.
+    ///
+    /// The data has one value per position, and the bars start after the given offset.
+    fn bars(
+        &self,
+        data: &[Vec<f64>],
+        offset: usize,
+        min: f64,
+        max: f64,
+        total_dots: usize,
+        dots_per_cell: usize,
+    ) -> Vec<Bar> {
+        let position = |value| self.dot_position(value, min, max, total_dots);
+        let mut bars = Vec::new();
+        for index in 0..longest(data) {
+            let values = data
+                .iter()
+                .enumerate()
+                .filter_map(|(series, data)| Some((series, *data.get(index)?)))
+                .filter(|(_, value)| value.is_finite());
+            match self.series_layout {
+                SeriesLayout::Grouped => {
+                    let start = position(self.baseline.unwrap_or(min));
+                    bars.extend(values.map(|(series, value)| {
+                        let slot =
+                            (offset + index) * self.slots_per_position() + series * self.bar_width;
+                        Bar {
+                            slots: slot..slot + self.bar_width,
+                            index,
+                            extent: Self::dot_extent(start, position(value), dots_per_cell),
+                            value,
+                            series,
+                        }
+                    }));
+                }
+                SeriesLayout::Stacked => {
+                    let base = self.baseline.unwrap_or(0.0);
+                    let (mut below, mut above) = (base, base);
+                    for (series, value) in values {
+                        let (start, end) = if value < 0.0 {
+                            below += value;
+                            (below - value, below)
+                        } else {
+                            above += value;
+                            (above - value, above)
+                        };
+                        let slot = (offset + index) * self.slots_per_position();
+                        bars.push(Bar {
+                            slots: slot..slot + self.bar_width,
+                            index,
+                            extent: Self::dot_extent(position(start), position(end), dots_per_cell),
+                            value,
+                            series,
+                        });
+                    }
+                }
+            }
+        }
+        bars
+    }
+
+    /// Renders the bars, building each cell from the dots covered by the bars that pass through it.
+    fn render_bars(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        data: &[Vec<f64>],
+        positions: &PositionMap,
+        min: f64,
+        max: f64,
+    ) {
+        let (bars_per_cell, dots_per_cell) = self.bar_style.cell_resolution(self.orientation);
+        let (category_cells, value_cells) = match self.orientation {
+            Orientation::Vertical => (area.width, area.height),
+            Orientation::Horizontal => (area.height, area.width),
+        };
+        let total_dots = value_cells as usize * dots_per_cell;
+        let bars = self.bars(
+            data,
+            positions.offset(),
+            min,
+            max,
+            total_dots,
+            dots_per_cell,
+        );
+        let len = longest(data);
+
+        // a bar may span several cells, and a cell may hold parts of several bars
+        let mut cells = vec![Vec::new(); category_cells as usize];
+        for bar in &bars {
+            let first = bar.slots.start / bars_per_cell;
+            let last = bar.slots.end.div_ceil(bars_per_cell);
+            for cell_bars in cells.iter_mut().take(last).skip(first) {
+                cell_bars.push(bar);
+            }
+        }
+
+        for (category, chunk) in cells.iter().enumerate() {
+            if chunk.is_empty() {
+                continue;
+            }
+            let columns = category * bars_per_cell..(category + 1) * bars_per_cell;
+            // the average value of a series' bars in this part of the graph
+            let mean = |series| {
+                let (sum, count) = chunk
+                    .iter()
+                    .filter(|bar| bar.series == series)
+                    .fold((0.0, 0.0), |(sum, count), bar| {
+                        (sum + bar.value, count + 1.0)
+                    });
+                sum / count
+            };
+            let brightness = self.brightness(chunk, len);
+            let heat = chunk
+                .iter()
+                .filter(|_| self.heatmap)
+                .max_by_key(|bar| bar.extent.len())
+                .map(|bar| {
+                    let color_value = self.solid_value(mean(bar.series), min, max);
+                    let color = self.color_for(bar.series, color_value);
+                    fade(color, brightness * HEATMAP_BRIGHTNESS)
+                });
+            let category_fraction = category as f64 / f64::from(category_cells);
+            let category = category as u16;
+            for cell in 0..value_cells {
+                let cell_dots = cell as usize * dots_per_cell..(cell as usize + 1) * dots_per_cell;
+                let mut mask = 0_u8;
+                let mut dominant: Option<(usize, &Bar)> = None;
+                for &bar in chunk {
+                    let dots =
+                        bar.extent.start.max(cell_dots.start)..bar.extent.end.min(cell_dots.end);
+                    let bar_columns =
+                        bar.slots.start.max(columns.start)..bar.slots.end.min(columns.end);
+                    if dots.is_empty() || bar_columns.is_empty() {
+                        continue;
+                    }
+                    for column in bar_columns.clone() {
+                        let column = column - columns.start;
+                        for dot in dots.clone() {
+                            let dot = dot - cell_dots.start;
+                            // the mask is laid out in rows from the top left of the cell, while
+                            // dots are counted along the bar from its base
+                            let bit = match self.orientation {
+                                Orientation::Vertical => {
+                                    (dots_per_cell - 1 - dot) * bars_per_cell + column
+                                }
+                                Orientation::Horizontal => column * dots_per_cell + dot,
+                            };
+                            mask |= 1 << bit;
+                        }
+                    }
+                    let count = dots.len() * bar_columns.len();
+                    if dominant.is_none_or(|(most, _)| count >= most) {
+                        dominant = Some((count, bar));
+                    }
+                }
+                let position = match self.orientation {
+                    Orientation::Vertical => (area.x + category, area.bottom() - 1 - cell),
+                    Orientation::Horizontal => (area.x + cell, area.y + category),
+                };
+                if let Some(heat) = heat {
+                    buf[position].set_bg(heat);
+                }
+                let Some((_, dominant)) = dominant else {
+                    continue;
+                };
+                // the cell is colored for the series that fills most of it, using the average value
+                // of that series' bars in this part of the graph
+                let bar_color = positions
+                    .indexes(dominant.index)
+                    .find_map(|index| self.bar_colors.get(&index).copied());
+                let color = bar_color.unwrap_or_else(|| {
+                    let color_value = match self.color_mode {
+                        ColorMode::Solid => self.solid_value(mean(dominant.series), min, max),
+                        ColorMode::VerticalGradient => {
+                            self.unscaled(f64::from(cell) / f64::from(value_cells), min, max)
+                        }
+                        ColorMode::HorizontalGradient => self.unscaled(category_fraction, min, max),
+                    };
+                    fade(self.color_for(dominant.series, color_value), brightness)
+                });
+                buf[position]
+                    .set_char(self.bar_style.symbol(self.orientation, mask))
+                    .set_fg(color);
+            }
+        }
+    }
+
+    /// The position of a value in dots from the base of the value axis.
+    fn dot_position(&self, value: f64, min: f64, max: f64, total_dots: usize) -> f64 {
+        self.fraction(value, min, max).clamp(0.0, 1.0) * total_dots as f64
+    }
+
+    /// The range of dots covered by a bar that spans from the baseline to the given position.
+    ///
+    /// When there is only one dot per cell, bars fill every cell that they touch. Otherwise they
+    /// are rounded to the nearest dot.
+    fn dot_extent(baseline: f64, position: f64, dots_per_cell: usize) -> Range<usize> {
+        let (start, end) = if position < baseline {
+            (position, baseline)
+        } else {
+            (baseline, position)
+        };
+        if dots_per_cell == 1 {
+            start.floor() as usize..end.ceil() as usize
+        } else {
+            start.round() as usize..end.round() as usize
+        }
+    }
+
+    /// The value that a bar with the given value is colored for with `ColorMode::Solid`.
+    ///
+    /// This places the value on the scale, so that the colors are spread the same way as the bars.
+    fn solid_value(&self, value: f64, min: f64, max: f64) -> f64 {
+        self.fraction(value, min, max).mul_add(max - min, min)
+    }
+
+    /// The brightness of the bars in a part of the graph, from the fade applied to older bars.
+    fn brightness(&self, bars: &[&Bar], len: usize) -> f64 {
+        let newest = bars.iter().map(|bar| bar.index).max().unwrap_or_default();
+        if len <= 1 {
+            return 1.0;
+        }
+        let age = (len - 1 - newest.min(len - 1)) as f64 / (len - 1) as f64;
+        1.0 - self.fade * age
+    }
+
+    /// The color for a cell of a bar from the given series.
+    ///
+    /// The color value is looked up in the gradient or thresholds, and depends on the color mode.
+    fn color_for(&self, series: usize, color_value: f64) -> Color {
+        let series = &self.series[series];
+        if let Some(color) = series.color {
+            return color;
+        }
+        if let Some(gradient) = &series.gradient {
+            return gradient_color(gradient.as_ref(), color_value);
+        }
+        if let Some(color) = self.threshold_color(color_value) {
+            return color;
+        }
+        self.gradient.as_ref().map_or(Color::Reset, |gradient| {
+            gradient_color(gradient.as_ref(), color_value)
+        })
+    }
+
+    /// The color of the highest threshold that the value reaches.
+    ///
+    /// Values below every threshold use the color of the lowest threshold.
+    fn threshold_color(&self, value: f64) -> Option<Color> {
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|(threshold, _)| value >= *threshold)
+            .or(self.thresholds.first())
+            .map(|(_, color)| *color)
+    }
+}
+
+impl Default for BarGraph<'_> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl Widget for BarGraph<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_graph(area, buf);
+    }
+}
+
+impl BarGraph<'_> {
+    /// Renders the graph, returning where the bars were placed.
+    fn render_graph(self, area: Rect, buf: &mut Buffer) -> BarLayout {
+        let mut data = self
+            .series
+            .iter()
+            .map(|series| series.data.clone())
+            .collect::<Vec<_>>();
+        if self.missing_values == MissingValues::Interpolate {
+            data.iter_mut()
+                .for_each(|series| missing::interpolate(series));
+        }
+        // the space left for the bars depends on the width of the tick labels, so it is found using
+        // the range of the data before the data is resampled to fit that space
+        let (min, max) = self.value_range(&data);
+        let bars = self.layout(area, &self.tick_labels(min, max)).bars;
+        let (positions, data) = self.resample(bars, &data);
+
+        let (min, max) = self.value_range(&data);
+        let tick_labels = self.tick_labels(min, max);
+        let layout = self.layout(area, &tick_labels);
+        self.render_gridlines(layout.bars, buf);
+        self.render_bars(layout.bars, buf, &data, &positions, min, max);
+        self.render_missing_markers(layout.bars, buf, &data, &positions, min, max);
+        self.render_annotations(layout.annotations, buf, &positions);
+        self.render_value_axis(layout.value_axis, buf, &tick_labels);
+        self.render_category_axis(layout.category_axis, buf, &positions);
+        BarLayout {
+            area: layout.bars,
+            orientation: self.orientation,
+            bars_per_cell: self.bar_style.cell_resolution(self.orientation).0,
+            slots_per_position: self.slots_per_position(),
+            positions,
+        }
+    }
+}
+
+impl StatefulWidget for BarGraph<'_> {
+    type State = BarGraphState;
+
+    /// Renders the samples of the state in place of the data of the first series, highlighting
+    /// the selected bar.
+    ///
+    /// The other series are rendered with their own data. With the default
+    /// [`Downsample::Truncate`], the most recent samples that fit in the area are shown. With any
+    /// other [`Downsample`], all retained samples are combined to fit the area.
+    ///
+    /// If the state has no capacity for samples, the graph's own data is rendered instead.
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if state.capacity() > 0 {
+            // the samples replace the data of the first series, keeping its name and colors
+            if self.series.is_empty() {
+                self.series.push(Series::new(Line::default(), Vec::new()));
+            }
+            let mut data = self.series[1..]
+                .iter()
+                .map(|series| series.data.clone())
+                .collect::<Vec<_>>();
+            data.push(state.autoscale_samples().collect());
+            let (min, max) = self.value_range(&data);
+            self.min = Some(min);
+            self.max = Some(max);
+
+            self.series[0].data = if self.downsample == Downsample::Truncate {
+                // truncating keeps the oldest values, so only the newest samples that fit are kept
+                let bars = self.layout(area, &self.tick_labels(min, max)).bars;
+                state.latest(self.available_positions(bars)).collect()
+            } else {
+                state.samples().collect()
+            };
+        }
+        // the selection is limited to the bars that are shown, which are placed the same way as
+        // when the graph is rendered
+        let data = self
+            .series
+            .iter()
+            .map(|series| series.data.clone())
+            .collect::<Vec<_>>();
+        let (min, max) = self.value_range(&data);
+        let bars = self.layout(area, &self.tick_labels(min, max)).bars;
+        let (positions, _) = self.resample(bars, &data);
+        state.clamp_selection(positions.shown());
+        if let Some(selected) = state.selected() {
+            self.bar_colors.insert(selected, self.selected_color);
+        }
+        state.set_layout(self.render_graph(area, buf));
+    }
+}
+
+/// The number of values in the longest series.
+fn longest(data: &[Vec<f64>]) -> usize {
+    data.iter().map(Vec::len).max().unwrap_or_default()
+}
+
+/// Looks up the color at the given value of a gradient.
+fn gradient_color(gradient: &dyn Gradient, value: f64) -> Color {
+    let rgba = gradient.at(value as f32).to_rgba8();
+    // TODO this can be changed to .into() in ratatui 0.30
+    Color::Rgb(rgba[0], rgba[1], rgba[2])
+}
+
+/// Dims an RGB color to the given brightness, leaving other colors unchanged.
+fn fade(color: Color, brightness: f64) -> Color {
+    let dim = |channel: u8| (f64::from(channel) * brightness).round() as u8;
+    match color {
+        Color::Rgb(r, g, b) if brightness < 1.0 => Color::Rgb(dim(r), dim(g), dim(b)),
+        color => color,
+    }
+}
+
+/// The offset of a position along an axis, given as a fraction of the axis length, in cells.
+fn axis_offset(fraction: f64, cells: u16) -> u16 {
+    (fraction * f64::from(cells.saturating_sub(1))).round() as u16
+}
+
+/// Formats a value for a tick label, with enough decimal places to tell the ticks apart.
+fn format_value(value: f64, range: f64) -> String {
+    let precision = (2.0 - range.log10().floor()).clamp(0.0, 6.0) as usize;
+    format!("{value:.precision$}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_gradient() {
+        let data = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        // check that we can use either a gradient or a boxed gradient
+        let _graph = BarGraph::new(data.clone()).with_gradient(colorgrad::preset::turbo());
+        let _graph = BarGraph::new(data).with_gradient(colorgrad::preset::turbo().boxed());
+    }
+
+    #[test]
+    fn braille() {
+        let data = (0..=40).map(|i| i as f64 * 0.125).collect();
+        let bar_graph = BarGraph::new(data);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 21, 10));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "                  ⢀⣴⡇",
+                "                ⢀⣴⣿⣿⡇",
+                "              ⢀⣴⣿⣿⣿⣿⡇",
+                "            ⢀⣴⣿⣿⣿⣿⣿⣿⡇",
+                "          ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⡇",
+                "        ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
+                "      ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
+                "    ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
+                "  ⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
+                "⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇",
+            ])
+        );
+    }
+
+    #[test]
+    fn solid() {
+        let data = vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5, 5.0];
+        let bar_graph = BarGraph::new(data).with_bar_style(BarStyle::Solid);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 10));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "          █",
+                "         ██",
+                "        ███",
+                "       ████",
+                "      █████",
+                "     ██████",
+                "    ███████",
+                "   ████████",
+                "  █████████",
+                " ██████████",
+            ])
+        );
+    }
+
+    #[test]
+    fn quadrant() {
+        let data = vec![
+            0.0, 0.25, 0.5, 0.75, 1.0, 1.25, 1.5, 1.75, 2.0, 2.25, 2.5, 2.75, 3.0, 3.25, 3.5, 3.75,
+            4.0, 4.25, 4.5, 4.75, 5.0,
+        ];
+        let bar_graph = BarGraph::new(data).with_bar_style(BarStyle::Quadrant);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 10));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "         ▗▌",
+                "        ▗█▌",
+                "       ▗██▌",
+                "      ▗███▌",
+                "     ▗████▌",
+                "    ▗█████▌",
+                "   ▗██████▌",
+                "  ▗███████▌",
+                " ▗████████▌",
+                "▗█████████▌",
+            ])
+        );
+    }
+
+    #[test]
+    fn octant() {
+        let data = (0..=40).map(|i| i as f64 * 0.125).collect();
+        let bar_graph = BarGraph::new(data).with_bar_style(BarStyle::Octant);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 21, 10));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
             buf,
             Buffer::with_lines(vec![
                 "                  𜺠𜷡▌",
@@ -493,4 +1735,879 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn baseline_solid() {
+        let data = vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_baseline(0.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 5));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["     █", "    ██", "   ███", "██    ", "█     ",])
+        );
+    }
+
+    #[test]
+    fn baseline_braille() {
+        let data = (-8..=8).map(f64::from).collect();
+        let bar_graph = BarGraph::new(data).with_baseline(0.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["      ⢀⣴⡇", "    ⢀⣴⣿⣿⡇", "⣿⣿⡿⠋     ", "⡿⠋       ",])
+        );
+    }
+
+    #[test]
+    fn baseline_extends_range() {
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Quadrant)
+            .with_baseline(0.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec![" ▟", "▟█"]));
+    }
+
+    #[test]
+    fn horizontal_solid() {
+        let data = vec![1.0, 0.75, 0.5, 0.3, 0.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_orientation(Orientation::Horizontal);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 5));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["████", "███ ", "██  ", "█▎  ", "    "])
+        );
+    }
+
+    #[test]
+    fn horizontal_braille() {
+        let data = (0..8).rev().map(f64::from).collect();
+        let bar_graph = BarGraph::new(data).with_orientation(Orientation::Horizontal);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["⣿⣿⡿⠋", "⠟⠁  "]));
+    }
+
+    #[test]
+    fn horizontal_quadrant() {
+        let data = vec![4.0, 3.0, 2.0, 1.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Quadrant)
+            .with_orientation(Orientation::Horizontal)
+            .with_min(0.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["█▛", "▛ "]));
+    }
+
+    #[test]
+    fn horizontal_baseline_inside_cell() {
+        // the baseline is one eighth of the way into the first cell, so the bars of 2, 5 and 6
+        // reach neither edge of that cell
+        let data = vec![-1.0, 2.0, 5.0, 6.0, 15.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_orientation(Orientation::Horizontal)
+            .with_baseline(0.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 5));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["▏ ", "▎ ", "▋ ", "▊ ", "🮋█"]));
+    }
+
+    #[test]
+    fn horizontal_baseline() {
+        let data = vec![-1.0, -0.5, 0.5, 1.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_orientation(Orientation::Horizontal)
+            .with_baseline(0.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["██  ", " █  ", "  █ ", "  ██"])
+        );
+    }
+
+    #[test]
+    fn value_ticks_and_category_labels() {
+        let data = vec![0.0, 25.0, 50.0, 75.0, 100.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_value_ticks(3)
+            .with_category_labels(["a", "b", "c", "d", "e"]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 5));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "100┤    █",
+                " 50┤   ██",
+                "   │  ███",
+                "  0┤ ████",
+                "    a c e",
+            ])
+        );
+    }
+
+    #[test]
+    fn value_ticks_without_data() {
+        let bar_graph = BarGraph::new(vec![])
+            .with_bar_style(BarStyle::Solid)
+            .with_value_ticks(2);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["1.0┤", "   │", "0.0┤"]));
+    }
+
+    #[test]
+    fn overlapping_category_labels() {
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_category_labels(["Mon", "Tue", "Wed", "Thu"]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["  ██", " ███", "Mon "]));
+    }
+
+    #[test]
+    fn gridlines() {
+        let data = vec![0.0, 0.5, 1.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_gridlines(1);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["  █", "┄██", " ██"]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().fg(Color::DarkGray));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn horizontal_value_ticks_and_category_labels() {
+        let data = vec![10.0, 5.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_orientation(Orientation::Horizontal)
+            .with_min(0.0)
+            .with_value_ticks(2)
+            .with_category_labels(["cpu", "io"]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "cpu ████████",
+                "io  ████    ",
+                "    ┬──────┬",
+                "    0.0 10.0",
+            ])
+        );
+    }
+
+    #[test]
+    fn axes_in_small_areas() {
+        for orientation in [Orientation::Vertical, Orientation::Horizontal] {
+            for (width, height) in [(0, 0), (1, 1), (2, 1), (1, 2), (3, 3)] {
+                let bar_graph = BarGraph::new(vec![1.0, 2.0, 3.0])
+                    .with_orientation(orientation)
+                    .with_value_ticks(3)
+                    .with_gridlines(2)
+                    .with_category_labels(["one", "two", "three"]);
+                let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+                Widget::render(bar_graph, buf.area, &mut buf);
+            }
+        }
+    }
+
+    #[test]
+    fn grouped_series() {
+        let bar_graph = BarGraph::from_series([
+            Series::new("read", vec![1.0, 3.0]).with_color(Color::Green),
+            Series::new("write", vec![2.0, 4.0]).with_color(Color::Red),
+        ])
+        .with_bar_style(BarStyle::Solid)
+        .with_min(0.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["   █", "  ██", " ███", "████"]);
+        expected.set_style(Rect::new(0, 3, 1, 1), Style::new().green());
+        expected.set_style(Rect::new(1, 2, 1, 2), Style::new().red());
+        expected.set_style(Rect::new(2, 1, 1, 3), Style::new().green());
+        expected.set_style(Rect::new(3, 0, 1, 4), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn stacked_series() {
+        let bar_graph = BarGraph::from_series([
+            Series::new("user", vec![1.0, 2.0, 1.0]).with_color(Color::Blue),
+            Series::new("system", vec![1.0, 1.0, 3.0]).with_color(Color::Magenta),
+        ])
+        .with_bar_style(BarStyle::Solid)
+        .with_series_layout(SeriesLayout::Stacked);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["  █", " ██", "███", "███"]);
+        expected.set_style(Rect::new(0, 3, 3, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 2, 1, 1), Style::new().magenta());
+        expected.set_style(Rect::new(1, 2, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(1, 1, 1, 1), Style::new().magenta());
+        expected.set_style(Rect::new(2, 0, 1, 3), Style::new().magenta());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn stacked_series_with_negative_values() {
+        let bar_graph = BarGraph::from_series([
+            Series::new("income", vec![2.0, 1.0]),
+            Series::new("costs", vec![-1.0, -2.0]),
+        ])
+        .with_bar_style(BarStyle::Solid)
+        .with_series_layout(SeriesLayout::Stacked);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["█ ", "██", "██", " █"]));
+    }
+
+    #[test]
+    fn legend() {
+        let bar_graph = BarGraph::from_series([
+            Series::new("read", vec![1.0]).with_color(Color::Green),
+            Series::new("write", vec![2.0]).with_color(Color::Red),
+        ]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 1));
+        Widget::render(bar_graph.legend(), buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["■ read  ■ write "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().green());
+        expected.set_style(Rect::new(8, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn downsample_max() {
+        let data = vec![1.0, 4.0, 2.0, 2.0, 3.0, 1.0, 0.0, 4.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_downsample(Downsample::Max);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["█  █", "█ ██", "████", "████"])
+        );
+    }
+
+    #[test]
+    fn downsample_labels_first_value_of_each_bar() {
+        let data = vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_downsample(Downsample::Mean)
+            .with_category_labels(["a", "b", "c", "d", "e", "f"]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["  █", " ██", "███", "a e"]));
+    }
+
+    #[test]
+    fn upsample_center() {
+        let data = vec![1.0, 2.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_upsample(Upsample::Center)
+            .with_category_labels(["a", "b"]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["  █  ", " ██  ", " a   "]));
+    }
+
+    #[test]
+    fn upsample_stretch() {
+        let data = vec![1.0, 2.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_upsample(Upsample::Stretch)
+            .with_category_labels(["a", "b"]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["  ██", "████", "a b "]));
+    }
+
+    #[test]
+    fn log10_scale() {
+        let data = vec![1.0, 10.0, 100.0, 1000.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_scale(Scale::Log10)
+            .with_value_ticks(4);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["1000┤   █", " 100┤  ██", "  10┤ ███", "   1┤ ███"])
+        );
+    }
+
+    #[test]
+    fn log10_scale_skips_values_that_are_not_positive() {
+        let data = vec![0.0, 10.0, 100.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_scale(Scale::Log10);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        // the minimum is raised to 10, the smallest positive value
+        assert_eq!(buf, Buffer::with_lines(vec!["  █", "  █"]));
+    }
+
+    #[test]
+    fn scale_fn() {
+        let data = vec![1.0, 4.0, 9.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_scale_fn(f64::sqrt);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["  █", " ██", "███"]));
+    }
+
+    #[test]
+    fn solid_color_uses_scale() {
+        let data = vec![1.0, 10.0, 100.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_color_mode(ColorMode::Solid)
+            .with_scale(Scale::Log10)
+            .with_gradient(
+                colorgrad::GradientBuilder::new()
+                    .colors(&[
+                        colorgrad::Color::new(0.0, 0.0, 0.0, 1.0),
+                        colorgrad::Color::new(1.0, 1.0, 1.0, 1.0),
+                    ])
+                    .domain(&[1.0, 100.0])
+                    .build::<colorgrad::LinearGradient>()
+                    .unwrap(),
+            );
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        // 10 is halfway between 1 and 100 on a logarithmic scale
+        assert_eq!(buf[(1, 0)].fg, Color::Rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn solid_thresholds() {
+        let data = vec![0.5, 0.8, 0.95];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_color_mode(ColorMode::Solid)
+            .with_min(0.0)
+            .with_max(1.0)
+            .with_thresholds([(0.9, Color::Red), (0.0, Color::Green), (0.7, Color::Yellow)]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["███"]);
+        expected[(0, 0)].set_fg(Color::Green);
+        expected[(1, 0)].set_fg(Color::Yellow);
+        expected[(2, 0)].set_fg(Color::Red);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn vertical_gradient_thresholds() {
+        let data = vec![1.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_thresholds([(0.0, Color::Green), (0.5, Color::Red)]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 4));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["█", "█", "█", "█"]);
+        expected.set_style(Rect::new(0, 0, 1, 2), Style::new().fg(Color::Red));
+        expected.set_style(Rect::new(0, 2, 1, 2), Style::new().fg(Color::Green));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn bar_color() {
+        let data = vec![1.0, 2.0, 3.0];
+        let bar_graph = BarGraph::from_series([Series::new("a", data).with_color(Color::Blue)])
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_bar_color(1, Color::Magenta);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["███"]);
+        expected.set_style(expected.area, Style::new().fg(Color::Blue));
+        expected[(1, 0)].set_fg(Color::Magenta);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn bar_color_when_downsampled() {
+        let data = vec![1.0, 1.0, 1.0, 1.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_downsample(Downsample::Max)
+            .with_bar_color(3, Color::Magenta);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["██"]);
+        expected[(1, 0)].set_fg(Color::Magenta);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn bar_width_and_gap() {
+        let data = vec![1.0, 2.0, 3.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_bar_width(2)
+            .with_bar_gap(1);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["      ██ ", "   ██ ██ ", "██ ██ ██ "])
+        );
+    }
+
+    #[test]
+    fn braille_bar_gap() {
+        let data = vec![4.0, 8.0, 4.0, 8.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Braille)
+            .with_min(0.0)
+            .with_bar_gap(1);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec![" ⡇ ⡇", "⡇⡇⡇⡇"]));
+    }
+
+    #[test]
+    fn grouped_series_bar_gap() {
+        let bar_graph = BarGraph::from_series([
+            Series::new("a", vec![1.0, 1.0]),
+            Series::new("b", vec![2.0, 2.0]),
+        ])
+        .with_bar_style(BarStyle::Solid)
+        .with_min(0.0)
+        .with_bar_gap(1);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec![" █  █ ", "██ ██ "]));
+    }
+
+    #[test]
+    fn stateful_selection() {
+        let data = vec![1.0, 2.0, 3.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_selected_color(Color::Yellow);
+        let mut state = BarGraphState::default();
+        state.select(usize::MAX);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        assert_eq!(state.selected(), Some(2));
+        let mut expected = Buffer::with_lines(vec!["███ "]);
+        expected[(2, 0)].set_fg(Color::Yellow);
+        assert_eq!(buf, expected);
+
+        state.select_next();
+        assert_eq!(state.selected(), Some(2));
+        state.select_previous();
+        assert_eq!(state.selected(), Some(1));
+    }
+
+    #[test]
+    fn select_before_render() {
+        let mut state = BarGraphState::default();
+        state.select_previous();
+        assert_eq!(state.selected(), None);
+        state.select_last();
+        assert_eq!(state.selected(), None);
+        state.select_next();
+        assert_eq!(state.selected(), Some(0));
+        state.select_last();
+        assert_eq!(state.selected(), Some(0));
+    }
+
+    #[test]
+    fn truncated_selection() {
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_selected_color(Color::Yellow);
+        let mut state = BarGraphState::default();
+        state.select(3);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        assert_eq!(state.selected(), Some(1));
+        let mut expected = Buffer::with_lines(vec!["██"]);
+        expected[(1, 0)].set_fg(Color::Yellow);
+        assert_eq!(buf, expected);
+
+        state.select_last();
+        assert_eq!(state.selected(), Some(1));
+        state.select_next();
+        assert_eq!(state.selected(), Some(1));
+    }
+
+    #[test]
+    fn index_at() {
+        let data = vec![1.0, 2.0, 3.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_value_ticks(2)
+            .with_value_format(|value| format!("{value:.0}"))
+            .with_bar_width(2)
+            .with_bar_gap(1);
+        let mut state = BarGraphState::default();
+        assert_eq!(state.index_at((2, 0)), None);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        // the tick labels and axis take up the first 2 columns
+        assert_eq!(state.index_at((1, 0)), None);
+        assert_eq!(state.index_at((2, 0)), Some(0));
+        assert_eq!(state.index_at((3, 1)), Some(0));
+        assert_eq!(state.index_at((5, 0)), Some(1));
+        assert_eq!(state.index_at((8, 0)), Some(2));
+        assert_eq!(state.index_at((11, 0)), None);
+    }
+
+    #[test]
+    fn horizontal_index_at() {
+        let data = vec![1.0, 2.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_orientation(Orientation::Horizontal)
+            .with_upsample(Upsample::Center);
+        let mut state = BarGraphState::default();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        assert_eq!(state.index_at((0, 0)), None);
+        assert_eq!(state.index_at((3, 1)), Some(0));
+        assert_eq!(state.index_at((0, 2)), Some(1));
+        assert_eq!(state.index_at((0, 3)), None);
+    }
+
+    /// A gradient from black to white over the domain 0 to 1.
+    fn grayscale() -> colorgrad::LinearGradient {
+        colorgrad::GradientBuilder::new()
+            .colors(&[
+                colorgrad::Color::new(0.0, 0.0, 0.0, 1.0),
+                colorgrad::Color::new(1.0, 1.0, 1.0, 1.0),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn horizontal_gradient() {
+        let data = vec![1.0, 1.0, 1.0, 1.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_color_mode(ColorMode::HorizontalGradient)
+            .with_gradient(grayscale())
+            .with_min(0.0)
+            .with_max(1.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["████", "████"]);
+        for (x, gray) in [0, 64, 128, 191].into_iter().enumerate() {
+            let style = Style::new().fg(Color::Rgb(gray, gray, gray));
+            expected.set_style(Rect::new(x as u16, 0, 1, 2), style);
+        }
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn fade() {
+        let data = vec![1.0, 1.0, 1.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_color_mode(ColorMode::Solid)
+            .with_gradient(grayscale())
+            .with_min(0.0)
+            .with_fade(0.5);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec!["███"]);
+        expected[(0, 0)].set_fg(Color::Rgb(128, 128, 128));
+        expected[(1, 0)].set_fg(Color::Rgb(191, 191, 191));
+        expected[(2, 0)].set_fg(Color::Rgb(255, 255, 255));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn heatmap() {
+        let data = vec![0.5, 1.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_color_mode(ColorMode::Solid)
+            .with_gradient(grayscale())
+            .with_min(0.0)
+            .with_max(1.0)
+            .with_heatmap(true);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(vec![" █ ", "██ "]);
+        expected.set_style(
+            Rect::new(0, 0, 1, 2),
+            Style::new().bg(Color::Rgb(51, 51, 51)),
+        );
+        expected.set_style(
+            Rect::new(0, 1, 1, 1),
+            Style::new().fg(Color::Rgb(128, 128, 128)),
+        );
+        expected.set_style(
+            Rect::new(1, 0, 1, 2),
+            Style::new()
+                .fg(Color::Rgb(255, 255, 255))
+                .bg(Color::Rgb(102, 102, 102)),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn missing_values_skip() {
+        let data = vec![1.0, f64::NAN, 3.0, f64::INFINITY];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["  █ ", "  █ ", "█ █ "]));
+    }
+
+    #[test]
+    fn missing_values_marker() {
+        let data = vec![1.0, f64::NAN, 3.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_missing_values(MissingValues::Marker);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["  █", "  █", "█×█"]));
+    }
+
+    #[test]
+    fn missing_values_interpolate() {
+        let data = vec![1.0, f64::NAN, 3.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_missing_values(MissingValues::Interpolate);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["  █", " ██", "███"]));
+    }
+
+    #[test]
+    fn annotations() {
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_annotation(1, "▼v2")
+            .with_annotation(2, "▼v3");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec![" ▼v2", "  ██", "████"]));
+    }
+
+    #[test]
+    fn horizontal_annotations() {
+        let data = vec![1.0, 2.0];
+        let bar_graph = BarGraph::new(data)
+            .with_bar_style(BarStyle::Solid)
+            .with_orientation(Orientation::Horizontal)
+            .with_min(0.0)
+            .with_annotation(1, "!");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Widget::render(bar_graph, buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["█   ", "██ !"]));
+    }
+
+    #[test]
+    fn stateful_scrolls_latest_samples() {
+        let mut state = BarGraphState::with_capacity(5);
+        state.extend((1..=8).map(f64::from));
+        assert_eq!(
+            state.samples().collect::<Vec<_>>(),
+            [4.0, 5.0, 6.0, 7.0, 8.0]
+        );
+
+        let bar_graph = BarGraph::default()
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 4));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        assert_eq!(buf, Buffer::with_lines(vec![" ██", "███", "███", "███"]));
+    }
+
+    #[test]
+    fn stateful_autoscale_window() {
+        let mut state = BarGraphState::with_capacity(4).with_autoscale_window(2);
+        state.extend([8.0, 1.0, 2.0, 3.0]);
+
+        let bar_graph = BarGraph::default().with_bar_style(BarStyle::Solid);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["█  █", "█  █"]));
+    }
+
+    #[test]
+    fn stateful_without_capacity_renders_data() {
+        let mut state = BarGraphState::default();
+        state.push(1.0);
+        assert!(state.is_empty());
+
+        let bar_graph = BarGraph::new(vec![1.0, 2.0]).with_bar_style(BarStyle::Solid);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        assert_eq!(buf, Buffer::with_lines(vec![" █"]));
+    }
+
+    #[test]
+    fn stateful_keeps_other_series() {
+        let mut state = BarGraphState::with_capacity(2);
+        state.extend([1.0, 2.0]);
+
+        let bar_graph = BarGraph::from_series([
+            Series::new("samples", vec![0.0]),
+            Series::new("limit", vec![2.0, 2.0]),
+        ])
+        .with_bar_style(BarStyle::Solid)
+        .with_min(0.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        assert_eq!(buf, Buffer::with_lines(vec![" ███", "████"]));
+    }
+
+    #[test]
+    fn stateful_downsample_max() {
+        let mut state = BarGraphState::with_capacity(4);
+        state.extend([1.0, 4.0, 2.0, 1.0]);
+
+        let bar_graph = BarGraph::default()
+            .with_bar_style(BarStyle::Solid)
+            .with_min(0.0)
+            .with_downsample(Downsample::Max);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 4));
+        StatefulWidget::render(bar_graph, buf.area, &mut buf, &mut state);
+
+        assert_eq!(buf, Buffer::with_lines(vec!["█ ", "█ ", "██", "██"]));
+    }
 }
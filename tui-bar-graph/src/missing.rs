@@ -0,0 +1,56 @@
+use strum::{Display, EnumString};
+
+/// How missing values are displayed.
+///
+/// Values that are not finite, such as `f64::NAN`, are treated as missing. They are never used
+/// to calculate the minimum and maximum of the graph.
+///
+/// - `Skip`: No bar is drawn for a missing value.
+/// - `Marker`: A marker is drawn at the base of the bar for a missing value.
+/// - `Interpolate`: Missing values are replaced by interpolating between their neighbors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum MissingValues {
+    /// No bar is drawn for a missing value.
+    #[default]
+    Skip,
+    /// A marker is drawn at the base of the bar for a missing value.
+    Marker,
+    /// Missing values are replaced by interpolating between their neighbors.
+    ///
+    /// Missing values at the start or end of a series have only one neighbor, so they are
+    /// skipped.
+    Interpolate,
+}
+
+/// Replaces the missing values between two present values by linear interpolation.
+pub(crate) fn interpolate(values: &mut [f64]) {
+    let mut previous: Option<usize> = None;
+    for index in 0..values.len() {
+        if !values[index].is_finite() {
+            continue;
+        }
+        if let Some(start) = previous.filter(|start| index - start > 1) {
+            let (from, to) = (values[start], values[index]);
+            let steps = (index - start) as f64;
+            for (step, value) in values[start + 1..index].iter_mut().enumerate() {
+                *value = (to - from).mul_add((step + 1) as f64 / steps, from);
+            }
+        }
+        previous = Some(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_gaps() {
+        let mut values = [f64::NAN, 1.0, f64::NAN, f64::NAN, 4.0, f64::INFINITY];
+        interpolate(&mut values);
+        assert!(values[0].is_nan());
+        assert_eq!(values[1..5], [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(values[5], f64::INFINITY);
+    }
+}
@@ -0,0 +1,271 @@
+use std::ops::Range;
+
+use strum::{Display, EnumString};
+
+/// How values are combined when there are more values than fit in the graph.
+///
+/// - `Truncate`: Values that don't fit are not displayed.
+/// - `Mean`: Each bar shows the mean of the values that it covers.
+/// - `Max`: Each bar shows the largest of the values that it covers.
+/// - `Min`: Each bar shows the smallest of the values that it covers.
+/// - `Last`: Each bar shows the last of the values that it covers.
+/// - `Sum`: Each bar shows the sum of the values that it covers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Downsample {
+    /// Values that don't fit are not displayed.
+    #[default]
+    Truncate,
+    /// Each bar shows the mean of the values that it covers.
+    Mean,
+    /// Each bar shows the largest of the values that it covers.
+    Max,
+    /// Each bar shows the smallest of the values that it covers.
+    Min,
+    /// Each bar shows the last of the values that it covers.
+    Last,
+    /// Each bar shows the sum of the values that it covers.
+    Sum,
+}
+
+/// How bars are placed when there are fewer values than fit in the graph.
+///
+/// - `Left`: The bars are placed at the start of the graph.
+/// - `Center`: The bars are placed in the middle of the graph.
+/// - `Stretch`: Each value is repeated to fill the graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Upsample {
+    /// The bars are placed at the start of the graph.
+    #[default]
+    Left,
+    /// The bars are placed in the middle of the graph.
+    Center,
+    /// Each value is repeated to fill the graph.
+    Stretch,
+}
+
+impl Downsample {
+    /// Combines the values covered by a single bar.
+    ///
+    /// Missing values are ignored, and the result is missing if every value is missing.
+    fn aggregate(self, values: &[f64]) -> Option<f64> {
+        let (&first, _) = values.split_first()?;
+        let mut present = values.iter().copied().filter(|value| value.is_finite());
+        Some(match self {
+            Self::Truncate => first,
+            Self::Mean => {
+                // 0 / 0 is NaN, so the mean of no values is missing
+                let (sum, count) =
+                    present.fold((0.0, 0.0), |(sum, count), value| (sum + value, count + 1.0));
+                sum / count
+            }
+            Self::Max => present.reduce(f64::max).unwrap_or(f64::NAN),
+            Self::Min => present.reduce(f64::min).unwrap_or(f64::NAN),
+            Self::Last => present.next_back().unwrap_or(f64::NAN),
+            Self::Sum => present.reduce(|sum, value| sum + value).unwrap_or(f64::NAN),
+        })
+    }
+}
+
+/// Maps the indexes of the data to positions along the category axis.
+///
+/// When the data doesn't fit the graph exactly, several values may share a position, or a value
+/// may span several positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PositionMap {
+    /// The number of values in the longest series.
+    len: usize,
+    /// The number of positions that the values are spread across.
+    positions: usize,
+    /// The number of empty positions before the first value.
+    offset: usize,
+    /// How values are combined when several share a position.
+    downsample: Downsample,
+}
+
+impl PositionMap {
+    /// Creates a map that fits `len` values into `available` positions.
+    pub(crate) fn new(
+        len: usize,
+        available: usize,
+        downsample: Downsample,
+        upsample: Upsample,
+    ) -> Self {
+        let (positions, offset) = if len > available {
+            (available, 0)
+        } else {
+            match upsample {
+                Upsample::Left => (len, 0),
+                Upsample::Center => (len, (available - len) / 2),
+                Upsample::Stretch => (available, 0),
+            }
+        };
+        Self {
+            len,
+            positions,
+            offset,
+            downsample,
+        }
+    }
+
+    /// The number of empty positions before the first value.
+    pub(crate) const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The number of values that are displayed, which is fewer than the number of values when
+    /// they are truncated to fit.
+    pub(crate) fn shown(&self) -> usize {
+        if self.downsample == Downsample::Truncate || self.positions == 0 {
+            self.len.min(self.positions)
+        } else {
+            self.len
+        }
+    }
+
+    /// The first index of the values shown at a position, including the offset.
+    ///
+    /// Returns `None` if no value is shown at the position.
+    pub(crate) fn index(&self, position: usize) -> Option<usize> {
+        let position = position.checked_sub(self.offset)?;
+        if position >= self.positions {
+            return None;
+        }
+        let index = self.indexes(position).start;
+        (index < self.len).then_some(index)
+    }
+
+    /// The indexes of the values shown at a position, not counting the offset.
+    pub(crate) fn indexes(&self, position: usize) -> Range<usize> {
+        if self.len > self.positions && self.downsample == Downsample::Truncate {
+            return position..position + 1;
+        }
+        let start = position * self.len / self.positions;
+        let end = ((position + 1) * self.len / self.positions).max(start + 1);
+        start..end
+    }
+
+    /// The first position that shows the value at an index, including the offset.
+    ///
+    /// Returns `None` if the value is not displayed.
+    pub(crate) fn position(&self, index: usize) -> Option<usize> {
+        if index >= self.len || self.positions == 0 {
+            return None;
+        }
+        let position = if self.positions >= self.len {
+            (index * self.positions).div_ceil(self.len)
+        } else if self.downsample == Downsample::Truncate {
+            index
+        } else {
+            ((index + 1) * self.positions - 1) / self.len
+        };
+        (position < self.positions).then_some(position + self.offset)
+    }
+
+    /// Resamples each series so that it has at most one value per position.
+    pub(crate) fn resample(&self, data: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        data.iter()
+            .map(|series| {
+                (0..self.positions)
+                    .map_while(|position| {
+                        let indexes = self.indexes(position);
+                        let values = series.get(indexes.start..indexes.end.min(series.len()))?;
+                        self.downsample.aggregate(values)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downsample() {
+        let data = vec![vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]];
+        let resample =
+            |downsample| PositionMap::new(6, 3, downsample, Upsample::Left).resample(&data);
+        assert_eq!(resample(Downsample::Truncate), [[1.0, 2.0, 3.0]]);
+        assert_eq!(resample(Downsample::Mean), [[1.5, 3.5, 5.5]]);
+        assert_eq!(resample(Downsample::Max), [[2.0, 4.0, 6.0]]);
+        assert_eq!(resample(Downsample::Min), [[1.0, 3.0, 5.0]]);
+        assert_eq!(resample(Downsample::Last), [[2.0, 4.0, 6.0]]);
+        assert_eq!(resample(Downsample::Sum), [[3.0, 7.0, 11.0]]);
+    }
+
+    #[test]
+    fn downsample_missing_values() {
+        let data = vec![vec![1.0, f64::NAN, f64::NAN, f64::NAN]];
+        let resample =
+            |downsample| PositionMap::new(4, 2, downsample, Upsample::Left).resample(&data);
+        assert_eq!(resample(Downsample::Mean)[0][0], 1.0);
+        assert_eq!(resample(Downsample::Last)[0][0], 1.0);
+        assert!(resample(Downsample::Mean)[0][1].is_nan());
+        assert!(resample(Downsample::Sum)[0][1].is_nan());
+    }
+
+    #[test]
+    fn upsample() {
+        let data = vec![vec![1.0, 2.0]];
+        let map = |upsample| PositionMap::new(2, 5, Downsample::Truncate, upsample);
+        assert_eq!(map(Upsample::Left).resample(&data), [[1.0, 2.0]]);
+        assert_eq!(map(Upsample::Center).resample(&data), [[1.0, 2.0]]);
+        assert_eq!(map(Upsample::Center).offset(), 1);
+        assert_eq!(
+            map(Upsample::Stretch).resample(&data),
+            [[1.0, 1.0, 1.0, 2.0, 2.0]]
+        );
+    }
+
+    #[test]
+    fn shown() {
+        let map = |len, available, downsample| {
+            PositionMap::new(len, available, downsample, Upsample::Stretch).shown()
+        };
+        assert_eq!(map(3, 2, Downsample::Truncate), 2);
+        assert_eq!(map(3, 2, Downsample::Mean), 3);
+        assert_eq!(map(2, 5, Downsample::Truncate), 2);
+        assert_eq!(map(3, 0, Downsample::Mean), 0);
+    }
+
+    #[test]
+    fn position() {
+        let downsampled = PositionMap::new(3, 2, Downsample::Mean, Upsample::Left);
+        assert_eq!(downsampled.position(0), Some(0));
+        assert_eq!(downsampled.position(1), Some(1));
+        assert_eq!(downsampled.position(2), Some(1));
+
+        let truncated = PositionMap::new(3, 2, Downsample::Truncate, Upsample::Left);
+        assert_eq!(truncated.position(1), Some(1));
+        assert_eq!(truncated.position(2), None);
+
+        let stretched = PositionMap::new(2, 5, Downsample::Truncate, Upsample::Stretch);
+        assert_eq!(stretched.position(0), Some(0));
+        assert_eq!(stretched.position(1), Some(3));
+
+        let centered = PositionMap::new(2, 5, Downsample::Truncate, Upsample::Center);
+        assert_eq!(centered.position(0), Some(1));
+        assert_eq!(centered.position(2), None);
+    }
+
+    #[test]
+    fn index() {
+        let downsampled = PositionMap::new(4, 2, Downsample::Mean, Upsample::Left);
+        assert_eq!(downsampled.index(0), Some(0));
+        assert_eq!(downsampled.index(1), Some(2));
+        assert_eq!(downsampled.index(2), None);
+
+        let centered = PositionMap::new(2, 5, Downsample::Truncate, Upsample::Center);
+        assert_eq!(centered.index(0), None);
+        assert_eq!(centered.index(1), Some(0));
+        assert_eq!(centered.index(2), Some(1));
+        assert_eq!(centered.index(3), None);
+
+        let stretched = PositionMap::new(2, 4, Downsample::Truncate, Upsample::Stretch);
+        assert_eq!(stretched.index(1), Some(0));
+        assert_eq!(stretched.index(2), Some(1));
+    }
+}
@@ -0,0 +1,57 @@
+use strum::{Display, EnumString};
+
+/// How values are mapped to positions along the value axis.
+///
+/// - `Linear`: Positions are proportional to the values.
+/// - `Log10`: Positions are proportional to the base 10 logarithm of the values.
+/// - `Symlog`: Like `Log10`, but linear near zero so that zero and negative values can be shown.
+/// - `Sqrt`: Positions are proportional to the square root of the values.
+///
+/// Use [`BarGraph::with_scale_fn`](crate::BarGraph::with_scale_fn) for a scale that isn't listed
+/// here.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum Scale {
+    /// Positions are proportional to the values.
+    #[default]
+    Linear,
+    /// Positions are proportional to the base 10 logarithm of the values.
+    ///
+    /// Zero and negative values can't be shown on this scale, so the minimum of the graph is
+    /// raised to the smallest positive value unless it is set explicitly.
+    Log10,
+    /// Positions are proportional to `sign(value) * log10(1 + |value|)`.
+    ///
+    /// This behaves like `Log10` for large values, and like `Linear` near zero.
+    Symlog,
+    /// Positions are proportional to the square root of the values.
+    ///
+    /// Negative values are mirrored, so `-4` is placed as far below zero as `4` is above it.
+    Sqrt,
+}
+
+impl Scale {
+    /// Maps a value to its position on the scale.
+    pub(crate) fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => value.log10(),
+            Self::Symlog => value.signum() * value.abs().ln_1p() / std::f64::consts::LN_10,
+            Self::Sqrt => value.signum() * value.abs().sqrt(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply() {
+        assert_eq!(Scale::Linear.apply(-2.5), -2.5);
+        assert_eq!(Scale::Log10.apply(1000.0), 3.0);
+        assert_eq!(Scale::Symlog.apply(-9.0), -1.0);
+        assert_eq!(Scale::Symlog.apply(0.0), 0.0);
+        assert_eq!(Scale::Sqrt.apply(-4.0), -2.0);
+    }
+}
@@ -0,0 +1,79 @@
+use colorgrad::Gradient;
+use ratatui_core::style::Color;
+use ratatui_core::text::Line;
+use strum::{Display, EnumString};
+
+/// A named set of values to display in a [`BarGraph`](crate::BarGraph).
+///
+/// Each series can have its own color or gradient. Series that have neither are colored using the
+/// gradient of the bar graph.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use tui_bar_graph::{BarGraph, Series, SeriesLayout};
+///
+/// let reads = Series::new("read", vec![3.0, 4.0, 2.0]).with_color(Color::Green);
+/// let writes = Series::new("write", vec![1.0, 2.0, 5.0]).with_color(Color::Red);
+/// let bar_graph =
+///     BarGraph::from_series([reads, writes]).with_series_layout(SeriesLayout::Stacked);
+/// ```
+pub struct Series<'g> {
+    /// The name of the series, shown in the legend.
+    pub(crate) name: Line<'g>,
+
+    /// The values to display as bars.
+    pub(crate) data: Vec<f64>,
+
+    /// A fixed color for the bars.
+    pub(crate) color: Option<Color>,
+
+    /// A gradient to use for coloring the bars.
+    pub(crate) gradient: Option<Box<dyn Gradient + 'g>>,
+}
+
+/// How the bars of multiple series are arranged at each position.
+///
+/// - `Grouped`: The bars for each series are placed side by side.
+/// - `Stacked`: The bars for each series are stacked on top of each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum SeriesLayout {
+    /// The bars for each series are placed side by side.
+    #[default]
+    Grouped,
+    /// The bars for each series are stacked on top of each other.
+    ///
+    /// Positive values stack up from the baseline (or zero if no baseline is set), and negative
+    /// values stack down from it.
+    Stacked,
+}
+
+impl<'g> Series<'g> {
+    /// Creates a new series with the given name and data.
+    pub fn new(name: impl Into<Line<'g>>, data: Vec<f64>) -> Self {
+        Self {
+            name: name.into(),
+            data,
+            color: None,
+            gradient: None,
+        }
+    }
+
+    /// Sets a fixed color for the bars of the series.
+    ///
+    /// This takes precedence over any gradient set on the series or the bar graph.
+    pub fn with_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the gradient to use for coloring the bars of the series.
+    ///
+    /// This takes precedence over the gradient set on the bar graph.
+    pub fn with_gradient(mut self, gradient: impl Gradient + 'g) -> Self {
+        self.gradient = Some(gradient.boxed());
+        self
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::VecDeque;
+
+use ratatui_core::layout::{Position, Rect};
+
+use crate::resample::PositionMap;
+use crate::Orientation;
+
+/// The state of a [`BarGraph`](crate::BarGraph) that displays a live stream of samples.
+///
+/// The state holds up to a fixed number of samples. Pushing a sample when the state is full drops
+/// the oldest one, so the graph scrolls left as new samples arrive. When the graph is rendered as a
+/// [`StatefulWidget`], the samples replace the data of its first series, and any other series keep
+/// their own data. With the default [`Downsample::Truncate`](crate::Downsample::Truncate), the most
+/// recent samples that fit in the area are shown. With any other
+/// [`Downsample`](crate::Downsample), all retained samples are combined to fit the area.
+///
+/// A state created with [`BarGraphState::default`] has no capacity for samples, and the graph
+/// displays its own data.
+///
+/// The state also holds the index of the selected bar, which is highlighted when the graph is
+/// rendered. Use [`select_next`](Self::select_next) and [`select_previous`](Self::select_previous)
+/// to move the selection with the keyboard, and [`index_at`](Self::index_at) to find the bar under
+/// the mouse.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_bar_graph::{BarGraph, BarGraphState};
+///
+/// # fn render(frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+/// let mut state = BarGraphState::with_capacity(1000).with_autoscale_window(200);
+/// state.push(0.5);
+/// state.push(0.7);
+/// frame.render_stateful_widget(BarGraph::default(), area, &mut state);
+/// # }
+/// ```
+///
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BarGraphState {
+    /// The retained samples, from oldest to newest.
+    samples: VecDeque<f64>,
+
+    /// The maximum number of samples to retain.
+    capacity: usize,
+
+    /// The number of recent samples that the minimum and maximum are calculated from.
+    autoscale_window: Option<usize>,
+
+    /// The index of the selected bar.
+    selected: Option<usize>,
+
+    /// Where the bars were placed when the graph was last rendered.
+    layout: Option<BarLayout>,
+}
+
+/// Where the bars of a graph were placed when it was rendered.
+///
+/// This is kept in the state to map positions in the terminal back to the indexes of the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BarLayout {
+    /// The area that the bars were rendered in.
+    pub(crate) area: Rect,
+    /// The direction that the bars grow in.
+    pub(crate) orientation: Orientation,
+    /// The number of bars that fit in a cell along the category axis.
+    pub(crate) bars_per_cell: usize,
+    /// The number of columns of dots used for each position in the data.
+    pub(crate) slots_per_position: usize,
+    /// How the indexes of the data map to positions along the category axis.
+    pub(crate) positions: PositionMap,
+}
+
+impl BarGraphState {
+    /// Creates a new state that retains up to `capacity` samples.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            autoscale_window: None,
+            selected: None,
+            layout: None,
+        }
+    }
+
+    /// Sets the number of recent samples that the minimum and maximum are calculated from.
+    ///
+    /// Calculating the range over a window that is longer than the visible part of the graph
+    /// keeps the scale steady as samples scroll past. If `None` (the default), the range is
+    /// calculated from all retained samples. A minimum or maximum set on the graph takes
+    /// precedence over the calculated value.
+    pub fn with_autoscale_window(mut self, window: impl Into<Option<usize>>) -> Self {
+        self.autoscale_window = window.into();
+        self
+    }
+
+    /// The maximum number of samples that the state retains.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of samples that the state currently holds.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if the state holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Appends a sample, dropping the oldest sample if the state is full.
+    pub fn push(&mut self, sample: f64) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Removes all samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// The samples that the state holds, from oldest to newest.
+    pub fn samples(&self) -> impl DoubleEndedIterator<Item = f64> + ExactSizeIterator + '_ {
+        self.samples.iter().copied()
+    }
+
+    /// The index of the selected bar, if any.
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the bar at the given index, or clears the selection if `None`.
+    ///
+    /// The index is clamped to the last bar when the graph is rendered.
+    pub fn select(&mut self, index: impl Into<Option<usize>>) {
+        self.selected = index.into();
+    }
+
+    /// Selects the next bar, or the first bar if none is selected.
+    pub fn select_next(&mut self) {
+        let next = self.selected.map_or(0, |index| index.saturating_add(1));
+        self.selected = Some(self.clamp(next));
+    }
+
+    /// Selects the previous bar, or the last bar if none is selected.
+    ///
+    /// The last bar is not known until the graph has been rendered, so nothing is selected before
+    /// then.
+    pub fn select_previous(&mut self) {
+        self.selected = match self.selected {
+            Some(index) => Some(self.clamp(index.saturating_sub(1))),
+            None => self.last(),
+        };
+    }
+
+    /// Selects the first bar.
+    pub fn select_first(&mut self) {
+        self.selected = Some(0);
+    }
+
+    /// Selects the last bar.
+    ///
+    /// Does nothing if the graph has not been rendered yet, as the last bar is not known.
+    pub fn select_last(&mut self) {
+        self.selected = self.last().or(self.selected);
+    }
+
+    /// The index of the value shown at a position in the terminal, such as the position of a mouse
+    /// click.
+    ///
+    /// Only the position along the category axis is considered, so any position in the column of
+    /// a vertical bar (or the row of a horizontal bar) maps to that bar. Returns `None` if the
+    /// graph has not been rendered, the position is outside the bars, or there is no bar there.
+    ///
+    /// When a terminal cell holds several bars, the first of them is returned.
+    pub fn index_at(&self, position: impl Into<Position>) -> Option<usize> {
+        let layout = self.layout?;
+        let position = position.into();
+        if !layout.area.contains(position) {
+            return None;
+        }
+        let cell = match layout.orientation {
+            Orientation::Vertical => position.x - layout.area.x,
+            Orientation::Horizontal => position.y - layout.area.y,
+        };
+        let slot = usize::from(cell) * layout.bars_per_cell;
+        layout.positions.index(slot / layout.slots_per_position)
+    }
+
+    /// The index of the last bar shown when the graph was last rendered.
+    fn last(&self) -> Option<usize> {
+        self.layout?.positions.shown().checked_sub(1)
+    }
+
+    /// Limits an index to the bars shown when the graph was last rendered.
+    fn clamp(&self, index: usize) -> usize {
+        self.last().map_or(index, |last| index.min(last))
+    }
+
+    /// Clamps the selection to the given number of bars.
+    pub(crate) fn clamp_selection(&mut self, len: usize) {
+        if len > 0 {
+            self.selected = self.selected.map(|index| index.min(len - 1));
+        }
+    }
+
+    /// Records where the bars were placed when the graph was rendered.
+    pub(crate) fn set_layout(&mut self, layout: BarLayout) {
+        self.layout = Some(layout);
+    }
+
+    /// The samples that the minimum and maximum are calculated from.
+    pub(crate) fn autoscale_samples(&self) -> impl Iterator<Item = f64> + '_ {
+        let window = self.autoscale_window.unwrap_or(self.samples.len());
+        self.latest(window)
+    }
+
+    /// The most recent `count` samples, from oldest to newest.
+    pub(crate) fn latest(&self, count: usize) -> impl Iterator<Item = f64> + '_ {
+        let skip = self.samples.len().saturating_sub(count);
+        self.samples.iter().skip(skip).copied()
+    }
+}
+
+impl Extend<f64> for BarGraphState {
+    fn extend<T: IntoIterator<Item = f64>>(&mut self, samples: T) {
+        for sample in samples {
+            self.push(sample);
+        }
+    }
+}
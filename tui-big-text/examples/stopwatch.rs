@@ -15,7 +15,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 use strum::EnumIs;
 use tokio::select;
-use tui_big_text::BigText;
+use tui_big_text::{BigText, SevenSegment};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -139,7 +139,11 @@ impl StopwatchApp {
         };
         let duration = format_duration(self.elapsed());
         let lines = vec![duration.into()];
-        BigText::builder().lines(lines).style(style).build()
+        BigText::builder()
+            .font(&SevenSegment)
+            .lines(lines)
+            .style(style)
+            .build()
     }
 
     /// Renders the splits as a list of lines.
@@ -0,0 +1,779 @@
+//! Animations of [`BigText`]: a scrolling marquee, a typewriter reveal and transitions from one
+//! text to another.
+//!
+//! The animations lay out the text as usual, and then move its pixels around before drawing them
+//! into cells. This lets text move in steps smaller than a cell when each cell holds more than one
+//! pixel.
+
+use std::ops::Range;
+use std::time::Duration;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Alignment, Rect};
+use ratatui_core::style::Style;
+use ratatui_core::widgets::StatefulWidget;
+
+use crate::{BigText, Glyph, PixelSize};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// A [`BigText`] that scrolls horizontally, like a news ticker.
+///
+/// The text scrolls left one pixel at a time, so with a [`PixelSize`] that has more than one pixel
+/// across a cell, such as `HalfWidth`, it moves in steps smaller than a cell. Once the end of the
+/// text has scrolled past, the text repeats after a gap. Each line scrolls as a whole, so the
+/// horizontal alignment, wrapping and fitting of the text are ignored. Gradients and shadows are
+/// not drawn.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::Frame;
+/// use tui_big_text::{BigText, Marquee, MarqueeState, PixelSize};
+///
+/// fn render(frame: &mut Frame, state: &mut MarqueeState) {
+///     let big_text = BigText::builder()
+///         .pixel_size(PixelSize::HalfWidth)
+///         .lines(vec!["Breaking news".into()])
+///         .build();
+///     frame.render_stateful_widget(Marquee::new(big_text), frame.area(), state);
+/// }
+///
+/// // scroll 20 pixels per second, advancing the state before each frame
+/// let mut state = MarqueeState::new(20);
+/// state.advance(Duration::from_millis(16));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Marquee<'a> {
+    text: BigText<'a>,
+    gap: Option<u16>,
+}
+
+impl<'a> Marquee<'a> {
+    /// Creates a marquee that scrolls the given text.
+    pub const fn new(text: BigText<'a>) -> Self {
+        Self { text, gap: None }
+    }
+
+    /// Sets the number of blank pixels between the end of the text and the start of its repeat.
+    ///
+    /// Defaults to the width of the area, so that the start of the text comes back into view as
+    /// its end scrolls out of view.
+    #[must_use]
+    pub const fn gap(mut self, pixels: u16) -> Self {
+        self.gap = Some(pixels);
+        self
+    }
+}
+
+/// The state of a [`Marquee`], which is how far its text has scrolled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MarqueeState {
+    offset: u64,
+    rate: Rate,
+}
+
+impl MarqueeState {
+    /// Creates a state that scrolls the given number of pixels per second when it is advanced.
+    pub const fn new(pixels_per_second: u32) -> Self {
+        Self {
+            offset: 0,
+            rate: Rate::new(pixels_per_second),
+        }
+    }
+
+    /// Scrolls the text by one pixel.
+    pub const fn tick(&mut self) {
+        self.offset = self.offset.wrapping_add(1);
+    }
+
+    /// Scrolls the text by the number of pixels that it moves in the elapsed time.
+    pub fn advance(&mut self, elapsed: Duration) {
+        self.offset = self.offset.wrapping_add(self.rate.advance(elapsed));
+    }
+
+    /// The number of pixels that the text has scrolled by.
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Scrolls the text back to the start.
+    pub const fn reset(&mut self) {
+        self.offset = 0;
+        self.rate.remainder = Duration::ZERO;
+    }
+}
+
+impl StatefulWidget for Marquee<'_> {
+    type State = MarqueeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let text = BigText {
+            alignment: Alignment::Left,
+            wrap: false,
+            fit: false,
+            ..self.text
+        };
+        let (pixel_size, graphemes) = text.pixel_layout(Rect::new(0, 0, u16::MAX, area.height));
+        let text = PixelText { graphemes };
+        let (step_x, _) = pixel_size.pixels_per_cell();
+        let gap = self
+            .gap
+            .map_or(i64::from(area.width) * i64::from(step_x), i64::from);
+        let period = text.width() + gap;
+        let Some(offset) = u64::try_from(period)
+            .ok()
+            .filter(|&period| period > 0)
+            .and_then(|period| i64::try_from(state.offset % period).ok())
+        else {
+            return;
+        };
+        render_pixels(area, buf, pixel_size, |x, y| {
+            text.pixel((x + offset) % period, y)
+        });
+    }
+}
+
+/// A [`BigText`] that is revealed one grapheme at a time, as if it is being typed.
+///
+/// Graphemes are revealed in reading order, and whitespace counts as a grapheme so that there is a
+/// pause between words. The text is laid out as a whole, so graphemes don't move as the text is
+/// revealed. Gradients and shadows are not drawn.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use tui_big_text::{BigText, Typewriter, TypewriterState};
+///
+/// fn render(frame: &mut Frame, state: &mut TypewriterState) {
+///     let big_text = BigText::builder().lines(vec!["Hello".into()]).build();
+///     frame.render_stateful_widget(Typewriter::new(big_text), frame.area(), state);
+/// }
+///
+/// // reveal 10 graphemes per second, or one grapheme on each tick
+/// let mut state = TypewriterState::new(10);
+/// state.tick();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Typewriter<'a> {
+    text: BigText<'a>,
+}
+
+impl<'a> Typewriter<'a> {
+    /// Creates a typewriter that reveals the given text.
+    pub const fn new(text: BigText<'a>) -> Self {
+        Self { text }
+    }
+}
+
+/// The state of a [`Typewriter`], which is how much of its text has been revealed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypewriterState {
+    revealed: usize,
+    rate: Rate,
+    /// The number of graphemes of the text. Not set until the first render call.
+    len: Option<usize>,
+}
+
+impl TypewriterState {
+    /// Creates a state that reveals the given number of graphemes per second when it is advanced.
+    pub const fn new(graphemes_per_second: u32) -> Self {
+        Self {
+            revealed: 0,
+            rate: Rate::new(graphemes_per_second),
+            len: None,
+        }
+    }
+
+    /// Reveals one more grapheme.
+    pub const fn tick(&mut self) {
+        self.revealed = self.revealed.saturating_add(1);
+    }
+
+    /// Reveals the number of graphemes that are typed in the elapsed time.
+    pub fn advance(&mut self, elapsed: Duration) {
+        let graphemes = usize::try_from(self.rate.advance(elapsed)).unwrap_or(usize::MAX);
+        self.revealed = self.revealed.saturating_add(graphemes);
+    }
+
+    /// The number of graphemes that have been revealed.
+    pub const fn revealed(&self) -> usize {
+        self.revealed
+    }
+
+    /// Returns `true` once the whole text has been revealed.
+    ///
+    /// The length of the text is not known until it has been rendered, so this is `false` before
+    /// the first render.
+    pub fn is_finished(&self) -> bool {
+        self.len.is_some_and(|len| self.revealed >= len)
+    }
+
+    /// Hides the text again.
+    pub const fn reset(&mut self) {
+        self.revealed = 0;
+        self.rate.remainder = Duration::ZERO;
+    }
+}
+
+impl StatefulWidget for Typewriter<'_> {
+    type State = TypewriterState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let (pixel_size, mut graphemes) = self.text.pixel_layout(area);
+        state.len = Some(graphemes.len());
+        graphemes.truncate(state.revealed);
+        let text = PixelText { graphemes };
+        render_pixels(area, buf, pixel_size, |x, y| text.pixel(x, y));
+    }
+}
+
+/// An animated change from one [`BigText`] to another.
+///
+/// Both texts are laid out in the area as usual, using the pixel size of the new text, and then
+/// moved by the [`TransitionEffect`]. Gradients and shadows are not drawn.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::Frame;
+/// use tui_big_text::{BigText, Transition, TransitionEffect, TransitionState};
+///
+/// fn render(frame: &mut Frame, state: &mut TransitionState) {
+///     let from = BigText::builder().lines(vec!["12:59".into()]).build();
+///     let to = BigText::builder().lines(vec!["13:00".into()]).build();
+///     let transition = Transition::new(from, to, TransitionEffect::Flip);
+///     frame.render_stateful_widget(transition, frame.area(), state);
+/// }
+///
+/// let mut state = TransitionState::with_duration(Duration::from_millis(300));
+/// state.advance(Duration::from_millis(16));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Transition<'a> {
+    from: BigText<'a>,
+    to: BigText<'a>,
+    effect: TransitionEffect,
+}
+
+impl<'a> Transition<'a> {
+    /// Creates a transition from one text to another.
+    pub const fn new(from: BigText<'a>, to: BigText<'a>, effect: TransitionEffect) -> Self {
+        Self { from, to, effect }
+    }
+}
+
+/// How a [`Transition`] replaces one text with another.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionEffect {
+    /// The old text slides up out of the area as the new text slides in from below.
+    #[default]
+    SlideUp,
+    /// The old text slides down out of the area as the new text slides in from above.
+    SlideDown,
+    /// The old text slides left out of the area as the new text slides in from the right.
+    SlideLeft,
+    /// The old text slides right out of the area as the new text slides in from the left.
+    SlideRight,
+    /// The old text folds up towards its middle row, and then the new text unfolds from the middle
+    /// of its rows, like the flaps of a flip clock.
+    Flip,
+}
+
+/// The state of a [`Transition`], which is how far it has progressed.
+///
+/// A transition is driven either by ticks or by elapsed time, depending on how the state is
+/// created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransitionState {
+    progress: Progress,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Progress {
+    Ticks { done: u32, total: u32 },
+    Timed { elapsed: Duration, total: Duration },
+}
+
+impl TransitionState {
+    /// Creates the state of a transition that finishes after the given number of ticks.
+    pub const fn with_ticks(ticks: u32) -> Self {
+        Self {
+            progress: Progress::Ticks {
+                done: 0,
+                total: ticks,
+            },
+        }
+    }
+
+    /// Creates the state of a transition that finishes after the given duration.
+    pub const fn with_duration(duration: Duration) -> Self {
+        Self {
+            progress: Progress::Timed {
+                elapsed: Duration::ZERO,
+                total: duration,
+            },
+        }
+    }
+
+    /// Advances the transition by one tick.
+    ///
+    /// This does nothing for a transition created with [`with_duration`](Self::with_duration).
+    pub fn tick(&mut self) {
+        if let Progress::Ticks { done, total } = &mut self.progress {
+            *done = done.saturating_add(1).min(*total);
+        }
+    }
+
+    /// Advances the transition by the elapsed time.
+    ///
+    /// This does nothing for a transition created with [`with_ticks`](Self::with_ticks).
+    pub fn advance(&mut self, elapsed: Duration) {
+        if let Progress::Timed {
+            elapsed: done,
+            total,
+        } = &mut self.progress
+        {
+            *done = done.saturating_add(elapsed).min(*total);
+        }
+    }
+
+    /// Returns `true` once the transition has finished, and only the new text is shown.
+    pub fn is_finished(&self) -> bool {
+        let (done, total) = self.fraction();
+        done == total
+    }
+
+    /// Restarts the transition from the old text.
+    pub const fn reset(&mut self) {
+        match &mut self.progress {
+            Progress::Ticks { done, .. } => *done = 0,
+            Progress::Timed { elapsed, .. } => *elapsed = Duration::ZERO,
+        }
+    }
+
+    /// The progress of the transition as a fraction, where the numerator is at most the
+    /// denominator and the denominator is never zero.
+    ///
+    /// The fraction is multiplied by distances in pixels when the transition is rendered, so it is
+    /// wide enough for that not to overflow even for the longest durations.
+    fn fraction(&self) -> (i128, i128) {
+        let (done, total) = match self.progress {
+            Progress::Ticks { done, total } => (i128::from(done), i128::from(total)),
+            Progress::Timed { elapsed, total } => {
+                let nanos =
+                    |duration: Duration| i128::try_from(duration.as_nanos()).unwrap_or(i128::MAX);
+                (nanos(elapsed), nanos(total))
+            }
+        };
+        if total == 0 {
+            (1, 1)
+        } else {
+            (done.min(total), total)
+        }
+    }
+}
+
+impl StatefulWidget for Transition<'_> {
+    type State = TransitionState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let (pixel_size, to) = self.to.pixel_layout(area);
+        let from = BigText {
+            pixel_size,
+            fit: false,
+            ..self.from
+        };
+        let (_, from) = from.pixel_layout(area);
+        let (from, to) = (PixelText { graphemes: from }, PixelText { graphemes: to });
+        let (done, total) = state.fraction();
+        let (step_x, step_y) = pixel_size.pixels_per_cell();
+        let width = i64::from(area.width) * i64::from(step_x);
+        let height = i64::from(area.height) * i64::from(step_y);
+
+        // the direction that the pixels are read from as the text slides, and the distance that
+        // the text slides
+        let (dx, dy, length) = match self.effect {
+            TransitionEffect::SlideUp => (0, 1, height),
+            TransitionEffect::SlideDown => (0, -1, height),
+            TransitionEffect::SlideLeft => (1, 0, width),
+            TransitionEffect::SlideRight => (-1, 0, width),
+            TransitionEffect::Flip => {
+                // the old text folds up over the first half, and the new text unfolds over the
+                // second half
+                let (text, scale) = if done * 2 < total {
+                    (&from, total - done * 2)
+                } else {
+                    (&to, done * 2 - total)
+                };
+                let rows = text.rows();
+                // twice the middle row, which keeps the maths in whole numbers
+                let middle = rows.start + rows.end;
+                render_pixels(area, buf, pixel_size, |x, y| {
+                    if scale == 0 {
+                        return None;
+                    }
+                    let y = (i128::from(2 * y + 1 - middle) * total).div_euclid(scale);
+                    // rows that are stretched past the range of an i64 are not part of the text
+                    let y = i64::try_from(y).ok()?;
+                    text.pixel(x, (y + middle - 1).div_euclid(2))
+                });
+                return;
+            }
+        };
+        // the shift is at most the length, so it fits back into an i64
+        let shift = i64::try_from(i128::from(length) * done / total).unwrap_or(length);
+        render_pixels(area, buf, pixel_size, |x, y| {
+            from.pixel(x + dx * shift, y + dy * shift).or_else(|| {
+                let shift = shift - length;
+                to.pixel(x + dx * shift, y + dy * shift)
+            })
+        });
+    }
+}
+
+/// Converts elapsed time into a number of steps at a fixed rate, carrying over the time that is
+/// left over for the next step.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+struct Rate {
+    per_second: u32,
+    remainder: Duration,
+}
+
+impl Rate {
+    const fn new(per_second: u32) -> Self {
+        Self {
+            per_second,
+            remainder: Duration::ZERO,
+        }
+    }
+
+    /// The number of steps that are taken in the elapsed time.
+    fn advance(&mut self, elapsed: Duration) -> u64 {
+        if self.per_second == 0 {
+            return 0;
+        }
+        let per_second = u128::from(self.per_second);
+        let nanos = self.remainder.saturating_add(elapsed).as_nanos();
+        let steps = nanos * per_second / NANOS_PER_SECOND;
+        let remainder = nanos - steps * NANOS_PER_SECOND / per_second;
+        self.remainder = Duration::from_nanos(u64::try_from(remainder).unwrap_or_default());
+        u64::try_from(steps).unwrap_or(u64::MAX)
+    }
+}
+
+/// A grapheme of a [`BigText`] laid out in pixels from the top left of the area that it is rendered
+/// in.
+#[derive(Debug, Clone)]
+pub(crate) struct PixelGrapheme {
+    /// The left of the cells that the grapheme is rendered in.
+    pub(crate) x: i64,
+    /// The top of the cells that the grapheme is rendered in.
+    pub(crate) y: i64,
+    /// The width of the cells that the grapheme is rendered in.
+    pub(crate) width: i64,
+    /// The height of the cells that the grapheme is rendered in.
+    pub(crate) height: i64,
+    pub(crate) style: Style,
+    pub(crate) glyph: Option<Glyph>,
+}
+
+impl PixelGrapheme {
+    /// The pixel at a position, or `None` if the position is outside the cells of the grapheme.
+    fn pixel(&self, x: i64, y: i64) -> Option<Pixel> {
+        let (x, y) = (x - self.x, y - self.y);
+        if !(0..self.width).contains(&x) || !(0..self.height).contains(&y) {
+            return None;
+        }
+        let set =
+            self.glyph
+                .as_ref()
+                .is_some_and(|glyph| match (u16::try_from(x), u16::try_from(y)) {
+                    (Ok(x), Ok(y)) => glyph.pixel(x, y),
+                    _ => false,
+                });
+        Some(Pixel {
+            set,
+            style: self.style,
+        })
+    }
+}
+
+/// A pixel of the text, along with the style of the grapheme that it is part of.
+#[derive(Debug, Clone, Copy)]
+struct Pixel {
+    set: bool,
+    style: Style,
+}
+
+/// The graphemes of a text, laid out in pixels.
+#[derive(Debug)]
+struct PixelText {
+    graphemes: Vec<PixelGrapheme>,
+}
+
+impl PixelText {
+    /// The pixel at a position, or `None` if the position is outside the cells of the text.
+    fn pixel(&self, x: i64, y: i64) -> Option<Pixel> {
+        self.graphemes
+            .iter()
+            .find_map(|grapheme| grapheme.pixel(x, y))
+    }
+
+    /// The number of pixels from the left of the area to the right of the text.
+    fn width(&self) -> i64 {
+        self.graphemes
+            .iter()
+            .map(|grapheme| grapheme.x + grapheme.width)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The rows of pixels that the text is rendered in.
+    fn rows(&self) -> Range<i64> {
+        let top = self.graphemes.iter().map(|grapheme| grapheme.y).min();
+        let bottom = self
+            .graphemes
+            .iter()
+            .map(|grapheme| grapheme.y + grapheme.height)
+            .max();
+        top.unwrap_or(0)..bottom.unwrap_or(0)
+    }
+}
+
+/// Draws pixels into the cells of an area.
+///
+/// `pixel` returns the pixel at a position, counted in pixels from the top left of the area. Cells
+/// with no pixels of the text are left as they are, and other cells are given the style of the
+/// first of their pixels that is part of the text.
+fn render_pixels(
+    area: Rect,
+    buf: &mut Buffer,
+    pixel_size: PixelSize,
+    pixel: impl Fn(i64, i64) -> Option<Pixel>,
+) {
+    let (step_x, step_y) = pixel_size.pixels_per_cell();
+    for position in area.positions() {
+        let left = i64::from(position.x - area.x) * i64::from(step_x);
+        let top = i64::from(position.y - area.y) * i64::from(step_y);
+        let mut style = None;
+        let mut rows = vec![0; usize::from(step_y)];
+        for (y, row) in (0..).zip(&mut rows) {
+            for x in 0..step_x {
+                if let Some(pixel) = pixel(left + i64::from(x), top + y) {
+                    style.get_or_insert(pixel.style);
+                    if pixel.set {
+                        *row |= 1 << x;
+                    }
+                }
+            }
+        }
+        if let Some(style) = style {
+            let cell = Glyph::new(step_x, rows);
+            buf[position]
+                .set_char(pixel_size.symbol_for_position(&cell, 0, 0))
+                .set_style(style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::text::Line;
+
+    use super::*;
+    use crate::Font;
+
+    /// A font where every glyph is a bar in the left column of a 2x4 glyph.
+    #[derive(Debug)]
+    struct BarFont;
+
+    impl Font for BarFont {
+        fn width(&self) -> u16 {
+            2
+        }
+
+        fn height(&self) -> u16 {
+            4
+        }
+
+        fn glyph(&self, grapheme: &str) -> Option<Glyph> {
+            (grapheme == "I").then(|| Glyph::new(2, vec![0b01; 4]))
+        }
+    }
+
+    fn big_text(text: &'static str, pixel_size: PixelSize) -> BigText<'static> {
+        BigText::builder()
+            .font(&BarFont)
+            .pixel_size(pixel_size)
+            .lines(vec![Line::from(text)])
+            .build()
+    }
+
+    #[test]
+    fn rate() {
+        let mut rate = Rate::new(10);
+        assert_eq!(rate.advance(Duration::from_millis(250)), 2);
+        assert_eq!(rate.remainder, Duration::from_millis(50));
+        assert_eq!(rate.advance(Duration::from_millis(60)), 1);
+        assert_eq!(rate.remainder, Duration::from_millis(10));
+        assert_eq!(Rate::new(0).advance(Duration::from_secs(1)), 0);
+    }
+
+    #[test]
+    fn marquee() {
+        let render = |state: &mut MarqueeState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+            let marquee = Marquee::new(big_text("II", PixelSize::QuarterHeight));
+            marquee.render(buf.area, &mut buf, state);
+            buf
+        };
+        let mut state = MarqueeState::new(100);
+        assert_eq!(render(&mut state), Buffer::with_lines(["█ █"]));
+        state.tick();
+        assert_eq!(render(&mut state), Buffer::with_lines([" █ "]));
+        // the text repeats after a gap the width of the area
+        state.advance(Duration::from_millis(40));
+        assert_eq!(state.offset(), 5);
+        assert_eq!(render(&mut state), Buffer::with_lines(["  █"]));
+    }
+
+    #[test]
+    fn marquee_sub_cell() {
+        let render = |offset: u64| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+            let marquee = Marquee::new(big_text("II", PixelSize::Octant));
+            let mut state = MarqueeState::default();
+            (0..offset).for_each(|_| state.tick());
+            marquee.render(buf.area, &mut buf, &mut state);
+            buf
+        };
+        assert_eq!(render(0), Buffer::with_lines(["▌▌ "]));
+        assert_eq!(render(1), Buffer::with_lines(["▐  "]));
+        assert_eq!(render(7), Buffer::with_lines([" ▐▐"]));
+        // an empty text without a gap has nothing to scroll
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let marquee = Marquee::new(big_text("", PixelSize::Octant)).gap(0);
+        marquee.render(buf.area, &mut buf, &mut MarqueeState::default());
+        assert_eq!(buf, Buffer::empty(buf.area));
+    }
+
+    #[test]
+    fn typewriter() {
+        let mut state = TypewriterState::new(10);
+        let render = |state: &mut TypewriterState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+            let typewriter = Typewriter::new(big_text("II", PixelSize::QuarterHeight));
+            typewriter.render(buf.area, &mut buf, state);
+            buf
+        };
+        assert_eq!(render(&mut state), Buffer::with_lines(["    "]));
+        assert!(!state.is_finished());
+        state.advance(Duration::from_millis(100));
+        assert_eq!(render(&mut state), Buffer::with_lines(["█   "]));
+        state.tick();
+        assert_eq!(render(&mut state), Buffer::with_lines(["█ █ "]));
+        assert!(state.is_finished());
+        state.reset();
+        assert_eq!(state.revealed(), 0);
+    }
+
+    #[test]
+    fn slide() {
+        let render = |effect: TransitionEffect, state: &mut TransitionState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+            let from = big_text("I", PixelSize::HalfHeight);
+            let to = big_text("II", PixelSize::HalfHeight);
+            Transition::new(from, to, effect).render(buf.area, &mut buf, state);
+            buf
+        };
+        let mut state = TransitionState::with_ticks(4);
+        assert_eq!(
+            render(TransitionEffect::SlideUp, &mut state),
+            Buffer::with_lines(["█   ", "█   "])
+        );
+        state.tick();
+        assert_eq!(
+            render(TransitionEffect::SlideUp, &mut state),
+            Buffer::with_lines(["█   ", "█ ▄ "])
+        );
+        assert_eq!(
+            render(TransitionEffect::SlideLeft, &mut state),
+            Buffer::with_lines(["   █", "   █"])
+        );
+        state.tick();
+        assert_eq!(
+            render(TransitionEffect::SlideDown, &mut state),
+            Buffer::with_lines(["█ █ ", "█   "])
+        );
+        (0..3).for_each(|_| state.tick());
+        assert!(state.is_finished());
+        assert_eq!(
+            render(TransitionEffect::SlideRight, &mut state),
+            Buffer::with_lines(["█ █ ", "█ █ "])
+        );
+    }
+
+    #[test]
+    fn flip() {
+        let render = |state: &mut TransitionState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+            let from = big_text("I", PixelSize::Full);
+            let to = big_text("II", PixelSize::Full);
+            Transition::new(from, to, TransitionEffect::Flip).render(buf.area, &mut buf, state);
+            buf
+        };
+        let mut state = TransitionState::with_duration(Duration::from_secs(4));
+        state.advance(Duration::from_secs(1));
+        assert_eq!(
+            render(&mut state),
+            Buffer::with_lines(["    ", "█   ", "█   ", "    "])
+        );
+        state.advance(Duration::from_secs(1));
+        assert_eq!(
+            render(&mut state),
+            Buffer::with_lines(["    ", "    ", "    ", "    "])
+        );
+        state.advance(Duration::from_secs(1));
+        assert_eq!(
+            render(&mut state),
+            Buffer::with_lines(["    ", "█ █ ", "█ █ ", "    "])
+        );
+        state.tick();
+        assert!(!state.is_finished());
+        state.advance(Duration::from_secs(2));
+        assert!(state.is_finished());
+    }
+
+    #[test]
+    fn long_duration() {
+        let render = |effect: TransitionEffect, state: &mut TransitionState| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+            let from = big_text("I", PixelSize::Full);
+            let to = big_text("II", PixelSize::Full);
+            Transition::new(from, to, effect).render(buf.area, &mut buf, state);
+            buf
+        };
+        // the longest duration renders the same as a short one at the same point
+        let mut long = TransitionState::with_duration(Duration::MAX);
+        // a quarter of the longest duration rounds down, so go just past it
+        long.advance(Duration::MAX / 4 + Duration::from_secs(1));
+        let mut short = TransitionState::with_ticks(4);
+        short.tick();
+        for effect in [TransitionEffect::Flip, TransitionEffect::SlideLeft] {
+            assert_eq!(render(effect, &mut long), render(effect, &mut short));
+        }
+        long.advance(Duration::MAX);
+        assert!(long.is_finished());
+        (0..3).for_each(|_| short.tick());
+        assert_eq!(
+            render(TransitionEffect::SlideUp, &mut long),
+            render(TransitionEffect::SlideUp, &mut short)
+        );
+    }
+}
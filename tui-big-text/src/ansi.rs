@@ -0,0 +1,102 @@
+//! Converts styled text into text with ANSI escape codes, for printing outside of a terminal UI.
+
+use std::fmt::Write;
+
+use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::text::Line;
+
+const RESET: &str = "\x1b[0m";
+
+/// The SGR parameters of each modifier.
+const MODIFIERS: [(Modifier, u8); 9] = [
+    (Modifier::BOLD, 1),
+    (Modifier::DIM, 2),
+    (Modifier::ITALIC, 3),
+    (Modifier::UNDERLINED, 4),
+    (Modifier::SLOW_BLINK, 5),
+    (Modifier::RAPID_BLINK, 6),
+    (Modifier::REVERSED, 7),
+    (Modifier::HIDDEN, 8),
+    (Modifier::CROSSED_OUT, 9),
+];
+
+/// Converts a line into a string, where each styled span is wrapped in the escape codes for its
+/// style and a reset.
+pub(crate) fn line(line: &Line<'_>) -> String {
+    let mut text = String::new();
+    for span in &line.spans {
+        let style = line.style.patch(span.style);
+        let codes = codes(style);
+        if codes.is_empty() {
+            text.push_str(&span.content);
+        } else {
+            let _ = write!(text, "\x1b[{codes}m{}{RESET}", span.content);
+        }
+    }
+    text
+}
+
+/// The SGR parameters for a style, separated by semicolons.
+fn codes(style: Style) -> String {
+    let mut codes = MODIFIERS
+        .iter()
+        .filter(|(modifier, _)| style.add_modifier.contains(*modifier))
+        .map(|(_, code)| code.to_string())
+        .collect::<Vec<_>>();
+    codes.extend(style.fg.and_then(|color| color_code(color, 30)));
+    codes.extend(style.bg.and_then(|color| color_code(color, 40)));
+    codes.join(";")
+}
+
+/// The SGR parameters for a color, where `base` is 30 for the foreground and 40 for the
+/// background. Returns `None` for the default color.
+fn color_code(color: Color, base: u8) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => base.to_string(),
+        Color::Red => (base + 1).to_string(),
+        Color::Green => (base + 2).to_string(),
+        Color::Yellow => (base + 3).to_string(),
+        Color::Blue => (base + 4).to_string(),
+        Color::Magenta => (base + 5).to_string(),
+        Color::Cyan => (base + 6).to_string(),
+        Color::Gray => (base + 7).to_string(),
+        Color::DarkGray => (base + 60).to_string(),
+        Color::LightRed => (base + 61).to_string(),
+        Color::LightGreen => (base + 62).to_string(),
+        Color::LightYellow => (base + 63).to_string(),
+        Color::LightBlue => (base + 64).to_string(),
+        Color::LightMagenta => (base + 65).to_string(),
+        Color::LightCyan => (base + 66).to_string(),
+        Color::White => (base + 67).to_string(),
+        Color::Indexed(index) => format!("{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+    };
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+    use ratatui_core::text::Span;
+
+    use super::*;
+
+    #[test]
+    fn style_codes() {
+        assert_eq!(codes(Style::new()), "");
+        assert_eq!(codes(Style::new().red().on_blue()), "31;44");
+        assert_eq!(codes(Style::new().bold().italic().light_cyan()), "1;3;96");
+        assert_eq!(
+            codes(Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Indexed(208))),
+            "38;2;1;2;3;48;5;208"
+        );
+        assert_eq!(codes(Style::new().fg(Color::Reset)), "");
+    }
+
+    #[test]
+    fn styled_line() {
+        let text = line(&Line::from(vec![Span::raw("a "), "b".green(), "c".into()]));
+        assert_eq!(text, "a \x1b[32mb\x1b[0mc");
+    }
+}
@@ -0,0 +1,404 @@
+//! A parser for fonts in the Glyph Bitmap Distribution Format (BDF).
+//!
+//! See <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5005.BDF_Spec.pdf> for the
+//! specification. Only the parts needed to render glyphs are read: the font bounding box, the
+//! ascent and descent, and the encoding, width and bitmap of each glyph.
+
+use crate::bitmap_font::glyph_row;
+use crate::{BitmapFont, FontError, Glyph};
+
+/// Returns `true` if the data looks like a BDF font.
+pub(crate) fn is_bdf(data: &[u8]) -> bool {
+    data.trim_ascii_start().starts_with(b"STARTFONT")
+}
+
+/// Parses a BDF font.
+pub(crate) fn parse(text: &str) -> Result<BitmapFont, FontError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("COMMENT"));
+    let metrics = Metrics::parse(&mut lines)?;
+    let mut font = BitmapFont::new(metrics.width()?, metrics.height);
+    loop {
+        let Some((number, line)) = lines.next() else {
+            return Err(invalid(text.lines().count(), "missing ENDFONT"));
+        };
+        match keyword(line) {
+            ("STARTCHAR", _) => {
+                if let Some((c, glyph)) = parse_glyph(&mut lines, &metrics)? {
+                    font.insert(c, glyph);
+                }
+            }
+            ("ENDFONT", _) => return Ok(font),
+            _ => return Err(invalid(number, "expected STARTCHAR or ENDFONT")),
+        }
+    }
+}
+
+/// The size and position of a bitmap relative to the origin of a glyph.
+#[derive(Debug, Clone, Copy)]
+struct BoundingBox {
+    width: i32,
+    height: i32,
+    x: i32,
+    y: i32,
+}
+
+impl BoundingBox {
+    fn parse(args: &str) -> Option<Self> {
+        let [width, height, x, y] = numbers(args)?;
+        (width >= 0 && height >= 0).then_some(Self {
+            width,
+            height,
+            x,
+            y,
+        })
+    }
+}
+
+/// The properties of the whole font, read from the lines before `CHARS`.
+#[derive(Debug)]
+struct Metrics {
+    bounding_box: BoundingBox,
+    /// The number of pixels above the baseline.
+    ascent: i32,
+    /// The height of a line, including the pixels below the baseline.
+    height: u16,
+    /// The default horizontal distance to the origin of the next glyph.
+    advance: Option<i32>,
+}
+
+impl Metrics {
+    fn parse<'t>(lines: &mut impl Iterator<Item = (usize, &'t str)>) -> Result<Self, FontError> {
+        let mut bounding_box = None;
+        let mut ascent = None;
+        let mut descent = None;
+        let mut advance = None;
+        let mut last = 0;
+        for (number, line) in lines.by_ref() {
+            last = number;
+            match keyword(line) {
+                ("FONTBOUNDINGBOX", args) => {
+                    bounding_box = BoundingBox::parse(args);
+                    if bounding_box.is_none() {
+                        return Err(invalid(number, "invalid FONTBOUNDINGBOX"));
+                    }
+                }
+                ("FONT_ASCENT", args) => {
+                    ascent = Some(number_arg(args, number, "invalid FONT_ASCENT")?)
+                }
+                ("FONT_DESCENT", args) => {
+                    descent = Some(number_arg(args, number, "invalid FONT_DESCENT")?)
+                }
+                ("DWIDTH", args) => advance = Some(number_arg(args, number, "invalid DWIDTH")?),
+                ("CHARS", _) => {
+                    let bounding_box =
+                        bounding_box.ok_or_else(|| invalid(number, "missing FONTBOUNDINGBOX"))?;
+                    let ascent = match ascent {
+                        Some(ascent) => ascent,
+                        None => bounding_box
+                            .height
+                            .checked_add(bounding_box.y)
+                            .ok_or_else(|| invalid(number, "invalid FONTBOUNDINGBOX"))?,
+                    };
+                    let descent = match descent {
+                        Some(descent) => descent,
+                        None => bounding_box
+                            .y
+                            .checked_neg()
+                            .ok_or_else(|| invalid(number, "invalid FONTBOUNDINGBOX"))?,
+                    };
+                    let height = ascent
+                        .checked_add(descent)
+                        .and_then(|height| u16::try_from(height).ok())
+                        .filter(|&height| height > 0)
+                        .ok_or_else(|| invalid(number, "invalid font height"))?;
+                    return Ok(Self {
+                        bounding_box,
+                        ascent,
+                        height,
+                        advance,
+                    });
+                }
+                _ => {}
+            }
+        }
+        Err(invalid(last, "missing CHARS"))
+    }
+
+    /// The width of the space left for characters without a glyph.
+    fn width(&self) -> Result<u16, FontError> {
+        glyph_width(self.advance.unwrap_or(self.bounding_box.width))
+    }
+}
+
+/// Parses the lines of a glyph after `STARTCHAR`, up to and including `ENDCHAR`.
+///
+/// Returns `None` for glyphs that are not mapped to a character.
+fn parse_glyph<'t>(
+    lines: &mut impl Iterator<Item = (usize, &'t str)>,
+    metrics: &Metrics,
+) -> Result<Option<(char, Glyph)>, FontError> {
+    let mut encoding = None;
+    let mut advance = metrics.advance;
+    let mut bounding_box = metrics.bounding_box;
+    let mut rows = vec![0; usize::from(metrics.height)];
+    let mut last = 0;
+    while let Some((number, line)) = lines.next() {
+        last = number;
+        match keyword(line) {
+            ("ENCODING", args) => encoding = Some(number_arg(args, number, "invalid ENCODING")?),
+            ("DWIDTH", args) => advance = Some(number_arg(args, number, "invalid DWIDTH")?),
+            ("BBX", args) => {
+                bounding_box =
+                    BoundingBox::parse(args).ok_or_else(|| invalid(number, "invalid BBX"))?;
+            }
+            ("BITMAP", _) => {
+                // the first bitmap row is at the top of the bounding box
+                let (top, bottom) = metrics
+                    .ascent
+                    .checked_sub(bounding_box.y)
+                    .and_then(|top| top.checked_sub(bounding_box.height))
+                    .and_then(|top| Some((top, top.checked_add(bounding_box.height)?)))
+                    .ok_or_else(|| invalid(number, "BBX is out of range"))?;
+                for y in top..bottom {
+                    let (number, line) = lines
+                        .next()
+                        .ok_or_else(|| invalid(number, "missing BITMAP rows"))?;
+                    let bytes = hex(line).ok_or_else(|| invalid(number, "invalid BITMAP row"))?;
+                    let row = shift(
+                        glyph_row(&bytes, u16::try_from(bounding_box.width).unwrap_or(64)),
+                        bounding_box.x,
+                    );
+                    if let Some(pixels) = usize::try_from(y).ok().and_then(|y| rows.get_mut(y)) {
+                        *pixels |= row;
+                    }
+                }
+            }
+            ("ENDCHAR", _) => {
+                let width = match advance {
+                    Some(advance) => advance,
+                    None => bounding_box
+                        .width
+                        .checked_add(bounding_box.x.max(0))
+                        .ok_or_else(|| invalid(number, "BBX is out of range"))?,
+                };
+                let width = glyph_width(width)?;
+                let c = encoding
+                    .and_then(|encoding| u32::try_from(encoding).ok())
+                    .and_then(char::from_u32);
+                return Ok(c.map(|c| (c, Glyph::new(width, rows))));
+            }
+            _ => {}
+        }
+    }
+    Err(invalid(last, "missing ENDCHAR"))
+}
+
+/// Splits a line into its keyword and the rest of the line.
+fn keyword(line: &str) -> (&str, &str) {
+    line.split_once(char::is_whitespace)
+        .map_or((line, ""), |(keyword, args)| (keyword, args.trim_start()))
+}
+
+/// Parses the first `N` whitespace separated numbers of a line, ignoring any others.
+fn numbers<const N: usize>(args: &str) -> Option<[i32; N]> {
+    let mut values = args.split_whitespace().map(str::parse);
+    let mut numbers = [0; N];
+    for number in &mut numbers {
+        *number = values.next()?.ok()?;
+    }
+    Some(numbers)
+}
+
+/// Parses the first number of a line.
+fn number_arg(args: &str, line: usize, reason: &'static str) -> Result<i32, FontError> {
+    numbers(args)
+        .map(|[number]| number)
+        .ok_or_else(|| invalid(line, reason))
+}
+
+/// Parses a row of hexadecimal digits into bytes.
+fn hex(line: &str) -> Option<Vec<u8>> {
+    if !line.is_ascii() || !line.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..line.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&line[index..index + 2], 16).ok())
+        .collect()
+}
+
+/// Moves a row of pixels right by `x` pixels, or left if `x` is negative.
+const fn shift(row: u64, x: i32) -> u64 {
+    match x {
+        0..64 => row << x,
+        -63..0 => row >> -x,
+        _ => 0,
+    }
+}
+
+fn glyph_width(width: i32) -> Result<u16, FontError> {
+    match u16::try_from(width.max(0)) {
+        Ok(width) if width <= 64 => Ok(width),
+        _ => Err(FontError::GlyphTooWide(width.unsigned_abs())),
+    }
+}
+
+const fn invalid(line: usize, reason: &'static str) -> FontError {
+    FontError::InvalidBdf { line, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::Font;
+
+    /// A 4x6 font with a descent of 1 pixel.
+    const FONT: &str = indoc! {"
+        STARTFONT 2.1
+        COMMENT a tiny test font
+        FONT -test-tiny-medium-r-normal--6-60-75-75-c-40-iso10646-1
+        SIZE 6 75 75
+        FONTBOUNDINGBOX 4 6 0 -1
+        STARTPROPERTIES 2
+        FONT_ASCENT 5
+        FONT_DESCENT 1
+        ENDPROPERTIES
+        CHARS 3
+        STARTCHAR A
+        ENCODING 65
+        SWIDTH 666 0
+        DWIDTH 4 0
+        BBX 3 5 0 0
+        BITMAP
+        40
+        A0
+        E0
+        A0
+        A0
+        ENDCHAR
+        STARTCHAR period
+        ENCODING 46
+        DWIDTH 2 0
+        BBX 1 1 1 0
+        BITMAP
+        80
+        ENDCHAR
+        STARTCHAR unmapped
+        ENCODING -1
+        DWIDTH 4 0
+        BBX 4 6 0 -1
+        BITMAP
+        F0
+        F0
+        F0
+        F0
+        F0
+        F0
+        ENDCHAR
+        ENDFONT
+    "};
+
+    #[test]
+    fn detect() {
+        assert!(is_bdf(FONT.as_bytes()));
+        assert!(!is_bdf(b"\x72\xb5\x4a\x86"));
+    }
+
+    #[test]
+    fn parse_font() {
+        let font = parse(FONT).unwrap();
+        assert_eq!(font.width(), 4);
+        assert_eq!(font.height(), 6);
+        assert_eq!(font.len(), 2);
+        assert_eq!(
+            font.glyph("A"),
+            Some(Glyph::new(4, vec![0b010, 0b101, 0b111, 0b101, 0b101, 0]))
+        );
+        assert_eq!(
+            font.glyph("."),
+            Some(Glyph::new(2, vec![0, 0, 0, 0, 0b10, 0]))
+        );
+        assert_eq!(font.glyph("B"), None);
+    }
+
+    #[test]
+    fn missing_bounding_box() {
+        let text = FONT.replace("FONTBOUNDINGBOX 4 6 0 -1\n", "");
+        assert!(matches!(
+            parse(&text),
+            Err(FontError::InvalidBdf {
+                line: 9,
+                reason: "missing FONTBOUNDINGBOX"
+            })
+        ));
+    }
+
+    #[test]
+    fn invalid_bitmap() {
+        let text = FONT.replace("A0\nE0", "A0\nXX");
+        assert!(matches!(
+            parse(&text),
+            Err(FontError::InvalidBdf {
+                line: 19,
+                reason: "invalid BITMAP row"
+            })
+        ));
+    }
+
+    #[test]
+    fn bounding_box_out_of_range() {
+        let text = FONT.replace("BBX 3 5 0 0", "BBX 3 5 0 -2147483648");
+        assert!(matches!(
+            parse(&text),
+            Err(FontError::InvalidBdf {
+                line: 16,
+                reason: "BBX is out of range"
+            })
+        ));
+        let text = FONT
+            .replace("DWIDTH 2 0", "")
+            .replace("BBX 1 1 1 0", "BBX 2147483647 1 1 0");
+        assert!(matches!(
+            parse(&text),
+            Err(FontError::InvalidBdf {
+                reason: "BBX is out of range",
+                ..
+            })
+        ));
+        let text = FONT.replace("FONT_ASCENT 5\n", "").replace(
+            "FONTBOUNDINGBOX 4 6 0 -1",
+            "FONTBOUNDINGBOX 4 2147483647 0 1",
+        );
+        assert!(matches!(
+            parse(&text),
+            Err(FontError::InvalidBdf {
+                line: 9,
+                reason: "invalid FONTBOUNDINGBOX"
+            })
+        ));
+    }
+
+    #[test]
+    fn glyph_too_wide() {
+        let text = FONT.replace("DWIDTH 2 0", "DWIDTH 65 0");
+        assert!(matches!(parse(&text), Err(FontError::GlyphTooWide(65))));
+    }
+
+    #[test]
+    fn truncated() {
+        let text = &FONT[..FONT.find("ENDFONT").unwrap()];
+        assert!(matches!(
+            parse(text),
+            Err(FontError::InvalidBdf {
+                reason: "missing ENDFONT",
+                ..
+            })
+        ));
+    }
+}
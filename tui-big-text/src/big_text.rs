@@ -1,18 +1,25 @@
 use std::cmp::min;
+use std::fmt;
 
 use derive_builder::Builder;
-use font8x8::UnicodeFonts;
-use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Alignment, Rect};
-use ratatui_core::style::Style;
-use ratatui_core::text::{Line, StyledGrapheme};
-use ratatui_core::widgets::Widget;
-
-use crate::PixelSize;
+use ratatui_core::buffer::{Buffer, Cell};
+use ratatui_core::layout::{Alignment, Position, Rect, Size, VerticalAlignment};
+use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::text::{Line, Span, StyledGrapheme};
+use ratatui_core::widgets::{self, Widget};
+
+use crate::animation::PixelGrapheme;
+use crate::font::DEFAULT_FONT;
+use crate::state::Hit;
+use crate::{
+    ansi, grapheme, BigTextState, FigletFont, Font, Glyph, Mirror, PixelSize, Rotation, Shadow,
+    TextGradient, TextPosition,
+};
 
 /// Displays one or more lines of text using 8x8 pixel characters.
 ///
-/// The text is rendered using the [font8x8](https://crates.io/crates/font8x8) crate.
+/// By default, the text is rendered using the [font8x8](https://crates.io/crates/font8x8) crate.
+/// Use the `font` method to render it with any other [`Font`].
 ///
 /// Using the `pixel_size` method, you can also chose, how 'big' a pixel should be. Currently a
 /// pixel of the 8x8 font can be represented by one full or half (horizontal/vertical/both)
@@ -82,6 +89,94 @@ pub struct BigText<'a> {
     /// Defaults to `Alignment::default()` (=> Alignment::Left)
     #[builder(default)]
     pub alignment: Alignment,
+
+    /// The vertical alignment of the text
+    ///
+    /// Defaults to `VerticalAlignment::default()` (=> VerticalAlignment::Top)
+    #[builder(default)]
+    pub vertical_alignment: VerticalAlignment,
+
+    /// The number of blank pixels between the glyphs of a line
+    ///
+    /// The space is rounded up to whole cells along with the glyph before it. Defaults to `0`.
+    #[builder(default)]
+    pub letter_spacing: u16,
+
+    /// The number of blank pixels between lines
+    ///
+    /// The space is rounded up to whole cells along with the line above it. Defaults to `0`.
+    #[builder(default)]
+    pub line_spacing: u16,
+
+    /// The font used to render the text
+    ///
+    /// Defaults to [`Font8x8`](crate::Font8x8). Fonts are compared by identity, so two widgets are
+    /// only equal if they use the same font value.
+    pub font: &'a dyn Font,
+
+    /// The character to render in place of graphemes that the font has no glyph for
+    ///
+    /// Defaults to `None`, which leaves a blank space the width of a glyph. Whitespace is never
+    /// replaced.
+    #[builder(default, setter(into, strip_option))]
+    pub fallback: Option<char>,
+
+    /// Whether lines that are too wide for the area are wrapped at word boundaries
+    ///
+    /// Defaults to `false`, which truncates lines that are too wide.
+    #[builder(default)]
+    pub wrap: bool,
+
+    /// Whether the largest pixel size that fits the area is used instead of `pixel_size`
+    ///
+    /// Pixel sizes are tried from the largest pixels to the smallest, and the smallest is used if
+    /// none fit. When wrapping is enabled, the text is wrapped separately for each pixel size.
+    ///
+    /// Defaults to `false`.
+    #[builder(default)]
+    pub fit: bool,
+
+    /// The gradient that colors the text
+    ///
+    /// Defaults to `None`, which colors the text with the style of the widget and its lines.
+    #[builder(default, setter(into, strip_option))]
+    pub gradient: Option<TextGradient<'a>>,
+
+    /// The drop shadow drawn behind the text
+    ///
+    /// Defaults to `None`.
+    #[builder(default, setter(into, strip_option))]
+    pub shadow: Option<Shadow>,
+
+    /// Whether only the outline of each glyph is drawn
+    ///
+    /// Defaults to `false`, which draws the glyphs filled.
+    #[builder(default)]
+    pub outline: bool,
+
+    /// The rotation of each glyph
+    ///
+    /// A quarter turn rotates the lines as well, so that they run down the area for
+    /// `Rotation::Degrees90` and up the area for `Rotation::Degrees270`. Glyphs that are turned
+    /// upside down are rotated in place, so lines still run from left to right. Lines are as thick
+    /// as the thickest rotated glyph. Defaults to `Rotation::None`.
+    #[builder(default)]
+    pub rotation: Rotation,
+
+    /// The mirroring of each glyph
+    ///
+    /// Glyphs are mirrored in place after they are rotated. Defaults to `Mirror::None`.
+    #[builder(default)]
+    pub mirror: Mirror,
+
+    /// The FIGlet font used to render the text instead of `font`
+    ///
+    /// FIGlet fonts draw characters with other characters rather than with pixels, so the pixel
+    /// size, letter spacing, fallback, wrapping, fitting, shadow, outline, rotation and mirroring
+    /// are not used, and the line spacing is a number of rows. The cursor and selection of a
+    /// [`BigTextState`] are not drawn. Defaults to `None`.
+    #[builder(default, setter(into, strip_option))]
+    pub figlet_font: Option<&'a FigletFont>,
 }
 
 impl BigText<'static> {
@@ -91,6 +186,119 @@ impl BigText<'static> {
     }
 }
 
+impl BigText<'_> {
+    /// The number of columns and rows that the text takes up when it is rendered.
+    ///
+    /// This accounts for the number of lines, the widths of the glyphs and the pixel size. Use it
+    /// to reserve space for the text in a layout, or to give it a size in a popup. Wrapping and
+    /// fitting depend on the area that the text is rendered in, so they are not applied here.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Constraint, Flex, Layout, Rect, Size};
+    /// use tui_big_text::{BigText, PixelSize};
+    ///
+    /// let big_text = BigText::builder()
+    ///     .pixel_size(PixelSize::Quadrant)
+    ///     .lines(vec!["Hello".into()])
+    ///     .build();
+    /// assert_eq!(big_text.size(), Size::new(20, 4));
+    ///
+    /// let area = Rect::new(0, 0, 80, 24);
+    /// let [centered] = Layout::horizontal([Constraint::Length(big_text.width())])
+    ///     .flex(Flex::Center)
+    ///     .areas(area);
+    /// ```
+    pub fn size(&self) -> Size {
+        if let Some(font) = self.figlet_font {
+            return self.figlet_size(font, &self.figlet_lines(font));
+        }
+        let size = self.measure(&self.shape(), self.pixel_size, u16::MAX);
+        if self.rotation.is_quarter_turn() {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        }
+    }
+
+    /// The number of columns that the text takes up when it is rendered.
+    ///
+    /// See [`size`](Self::size) for details.
+    pub fn width(&self) -> u16 {
+        self.size().width
+    }
+
+    /// The number of rows that the text takes up when it is rendered.
+    ///
+    /// See [`size`](Self::size) for details.
+    pub fn height(&self) -> u16 {
+        self.size().height
+    }
+
+    /// Renders the text into lines of styled text, without a terminal or a [`Buffer`].
+    ///
+    /// Each row of cells becomes a line, with a span for each run of cells that have the same
+    /// style. Blank cells at the end of each row are left out. The text is rendered at its
+    /// [`size`](Self::size), so fitting is not applied.
+    ///
+    /// Use the [`Display`](fmt::Display) implementation to get the text without styles, or
+    /// [`to_ansi_string`](Self::to_ansi_string) to get it with ANSI escape codes.
+    pub fn to_lines(&self) -> Vec<Line<'static>> {
+        let text = BigText {
+            fit: false,
+            ..self.clone()
+        };
+        let mut buf = Buffer::empty(Rect::from((Position::ORIGIN, self.size())));
+        Widget::render(text, buf.area, &mut buf);
+        (0..buf.area.height).map(|y| buffer_row(&buf, y)).collect()
+    }
+
+    /// Renders the text into a string with ANSI escape codes for its colors and modifiers, such
+    /// as a banner to print to stdout.
+    ///
+    /// Lines are separated by `\n`, and there is no newline after the last line. See
+    /// [`to_lines`](Self::to_lines) for how the text is rendered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Stylize;
+    /// use tui_big_text::{BigText, PixelSize};
+    ///
+    /// let banner = BigText::builder()
+    ///     .pixel_size(PixelSize::Quadrant)
+    ///     .lines(vec!["mytool".cyan().into()])
+    ///     .build();
+    /// println!("{}", banner.to_ansi_string());
+    ///
+    /// // or without styles
+    /// println!("{banner}");
+    /// ```
+    pub fn to_ansi_string(&self) -> String {
+        self.to_lines()
+            .iter()
+            .map(ansi::line)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Renders the text as plain text without styles, with a line for each row of cells.
+///
+/// See [`BigText::to_lines`] for how the text is rendered.
+impl fmt::Display for BigText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, line) in self.to_lines().iter().enumerate() {
+            if index > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
 impl BigTextBuilder<'_> {
     /// Set the alignment of the text.
     pub fn left_aligned(&mut self) -> &mut Self {
@@ -106,6 +314,21 @@ impl BigTextBuilder<'_> {
     pub fn centered(&mut self) -> &mut Self {
         self.alignment(Alignment::Center)
     }
+
+    /// Set the vertical alignment of the text.
+    pub fn top_aligned(&mut self) -> &mut Self {
+        self.vertical_alignment(VerticalAlignment::Top)
+    }
+
+    /// Set the vertical alignment of the text.
+    pub fn vertically_centered(&mut self) -> &mut Self {
+        self.vertical_alignment(VerticalAlignment::Center)
+    }
+
+    /// Set the vertical alignment of the text.
+    pub fn bottom_aligned(&mut self) -> &mut Self {
+        self.vertical_alignment(VerticalAlignment::Bottom)
+    }
 }
 
 impl<'a> BigTextBuilder<'a> {
@@ -116,89 +339,734 @@ impl<'a> BigTextBuilder<'a> {
             style: self.style.unwrap_or_default(),
             pixel_size: self.pixel_size.unwrap_or_default(),
             alignment: self.alignment.unwrap_or_default(),
+            vertical_alignment: self.vertical_alignment.unwrap_or_default(),
+            letter_spacing: self.letter_spacing.unwrap_or_default(),
+            line_spacing: self.line_spacing.unwrap_or_default(),
+            font: self.font.unwrap_or(&DEFAULT_FONT),
+            fallback: self.fallback.flatten(),
+            wrap: self.wrap.unwrap_or_default(),
+            fit: self.fit.unwrap_or_default(),
+            gradient: self.gradient.flatten(),
+            shadow: self.shadow.flatten(),
+            outline: self.outline.unwrap_or_default(),
+            rotation: self.rotation.unwrap_or_default(),
+            mirror: self.mirror.unwrap_or_default(),
+            figlet_font: self.figlet_font.flatten(),
         }
     }
 }
 
 impl Widget for BigText<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let layout = layout(area, &self.pixel_size, self.alignment, &self.lines);
-        for (line, line_layout) in self.lines.iter().zip(layout) {
-            for (g, cell) in line.styled_graphemes(self.style).zip(line_layout) {
-                render_symbol(g, cell, buf, &self.pixel_size);
+        if let Some(font) = self.figlet_font {
+            self.render_figlet(font, area, buf);
+            return;
+        }
+        let lines = self.shape();
+        let pixel_size = self.fitted_pixel_size(&lines, area);
+        let placements = self.layout(&lines, pixel_size, area);
+        self.render_placements(&placements, pixel_size, area, buf);
+    }
+}
+
+/// Renders the text as an editable field, with the cursor and selection of the state drawn as
+/// inverted cells.
+impl widgets::StatefulWidget for BigText<'_> {
+    type State = BigTextState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if let Some(font) = self.figlet_font {
+            self.render_figlet(font, area, buf);
+            state.hits.clear();
+            return;
+        }
+        let mut lines = self.shape();
+        // a cursor at the end of a line is drawn as a blank glyph after the line
+        let cursor = state.cursor().and_then(|cursor| {
+            let graphemes = lines.get_mut(cursor.line)?;
+            let end = graphemes.len();
+            if cursor.grapheme >= end {
+                let style = self.style.patch(self.lines[cursor.line].style);
+                graphemes.push(ShapedGrapheme {
+                    grapheme: StyledGrapheme::new("", style),
+                    glyph: None,
+                    width: self.glyph_size().width,
+                    position: TextPosition::new(cursor.line, end),
+                });
+            }
+            Some(TextPosition::new(cursor.line, cursor.grapheme.min(end)))
+        });
+        let pixel_size = self.fitted_pixel_size(&lines, area);
+        let placements = self.layout(&lines, pixel_size, area);
+        self.render_placements(&placements, pixel_size, area, buf);
+
+        let selection = state.selection();
+        for placement in &placements {
+            let position = placement.grapheme.position;
+            let selected = selection
+                .as_ref()
+                .is_some_and(|selection| selection.contains(&position));
+            if selected || cursor == Some(position) {
+                buf.set_style(
+                    placement.cell,
+                    Style::new().add_modifier(Modifier::REVERSED),
+                );
             }
         }
+        state.hits = placements
+            .iter()
+            .filter(|placement| !placement.grapheme.is_cursor())
+            .map(|placement| Hit {
+                cell: placement.cell,
+                position: placement.grapheme.position,
+            })
+            .collect();
     }
 }
 
-/// Chunk the area into as many x*y cells as possible returned as a 2D iterator of `Rect`s
-/// representing the rows of cells. The size of each cell depends on given font size
-fn layout<'a>(
-    area: Rect,
-    pixel_size: &PixelSize,
-    alignment: Alignment,
-    lines: &'a [Line<'a>],
-) -> impl IntoIterator<Item = impl IntoIterator<Item = Rect>> + 'a {
-    let (step_x, step_y) = pixel_size.pixels_per_cell();
-    let width = 8_u16.div_ceil(step_x);
-    let height = 8_u16.div_ceil(step_y);
-
-    (area.top()..area.bottom())
-        .step_by(height as usize)
-        .zip(lines.iter())
-        .map(move |(y, line)| {
-            let offset = get_alignment_offset(area.width, width, alignment, line);
-            (area.left() + offset..area.right())
-                .step_by(width as usize)
-                .map(move |x| {
-                    let width = min(area.right() - x, width);
-                    let height = min(area.bottom() - y, height);
-                    Rect::new(x, y, width, height)
+impl BigText<'_> {
+    /// Renders graphemes into the cells that they have been laid out in.
+    fn render_placements(
+        &self,
+        placements: &[Placement<'_, '_>],
+        pixel_size: PixelSize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        for placement in placements {
+            for position in placement.cell.positions() {
+                buf[position]
+                    .set_char(' ')
+                    .set_style(self.cell_style(placement, position));
+            }
+        }
+        // shadows are drawn before the glyphs, so that the glyphs are drawn over them
+        if let Some(shadow) = self.shadow {
+            let (step_x, step_y) = pixel_size.pixels_per_cell();
+            for placement in placements {
+                let Some(glyph) = &placement.grapheme.glyph else {
+                    continue;
+                };
+                let glyph = glyph.clone().shifted(shadow.x, shadow.y);
+                let shadow_area = Rect::new(
+                    placement.cell.x,
+                    placement.cell.y,
+                    glyph.width().div_ceil(step_x),
+                    glyph.height().div_ceil(step_y),
+                )
+                .intersection(area);
+                render_glyph(&glyph, shadow_area, buf, &pixel_size, |_| {
+                    Style::new().fg(shadow.color)
+                });
+            }
+        }
+        for placement in placements {
+            if let Some(glyph) = &placement.grapheme.glyph {
+                render_glyph(glyph, placement.cell, buf, &pixel_size, |position| {
+                    self.cell_style(placement, position)
+                });
+            }
+        }
+    }
+}
+
+/// Where a grapheme is rendered.
+#[derive(Debug, Clone, Copy)]
+struct Placement<'s, 'g> {
+    /// The cells that the glyph of the grapheme is drawn in.
+    cell: Rect,
+    /// The cells of the whole row of text that the grapheme is part of, which may extend past the
+    /// area that the text is rendered in.
+    row: Rect,
+    grapheme: &'s ShapedGrapheme<'g>,
+}
+
+/// The sizes of glyphs and of the spaces between them in cells, for a single pixel size.
+///
+/// Widths are measured along the lines of text and heights across them, which are the other way
+/// round when the lines run down or up the area.
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+    /// The number of pixels in the width of a cell.
+    step_x: u16,
+    /// The number of blank pixels between glyphs.
+    letter_spacing: u16,
+    /// The number of rows that a line of glyphs takes up.
+    line_height: u16,
+    /// The number of rows from the top of one line to the top of the next.
+    line_pitch: u16,
+}
+
+impl Metrics {
+    /// The number of columns that a grapheme takes up, including the letter spacing after it
+    /// unless it is the last grapheme of a row.
+    const fn columns(&self, grapheme: &ShapedGrapheme<'_>, last: bool) -> u16 {
+        let spacing = if last { 0 } else { self.letter_spacing };
+        grapheme.width.saturating_add(spacing).div_ceil(self.step_x)
+    }
+
+    /// The number of columns that a row of graphemes takes up.
+    fn row_width(&self, row: &[ShapedGrapheme<'_>]) -> u16 {
+        row.iter().enumerate().fold(0, |width, (index, grapheme)| {
+            width.saturating_add(self.columns(grapheme, index + 1 == row.len()))
+        })
+    }
+
+    /// The number of rows that the given number of lines take up.
+    fn height(&self, lines: usize) -> u16 {
+        let Some(spaced) = lines.checked_sub(1) else {
+            return 0;
+        };
+        let spaced = u16::try_from(spaced).unwrap_or(u16::MAX);
+        self.line_pitch
+            .saturating_mul(spaced)
+            .saturating_add(self.line_height)
+    }
+}
+
+/// A grapheme of the text and the glyph that it is rendered with.
+#[derive(Debug, Clone)]
+struct ShapedGrapheme<'a> {
+    grapheme: StyledGrapheme<'a>,
+    glyph: Option<Glyph>,
+    /// The width of the glyph in pixels.
+    width: u16,
+    position: TextPosition,
+}
+
+impl ShapedGrapheme<'_> {
+    /// Returns `true` if the grapheme is whitespace. The cursor at the end of a line is not.
+    fn is_whitespace(&self) -> bool {
+        !self.is_cursor() && self.grapheme.symbol.chars().all(char::is_whitespace)
+    }
+
+    /// Returns `true` if this is the blank glyph that is drawn for a cursor at the end of a line.
+    fn is_cursor(&self) -> bool {
+        self.grapheme.symbol.is_empty()
+    }
+}
+
+impl BigText<'_> {
+    /// Looks up the glyphs for the graphemes of each line.
+    fn shape(&self) -> Vec<Vec<ShapedGrapheme<'_>>> {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(line_index, line)| {
+                line.styled_graphemes(self.style)
+                    .enumerate()
+                    .map(|(index, grapheme)| {
+                        let glyph = self.glyph(grapheme.symbol);
+                        let width = glyph
+                            .as_ref()
+                            .map_or(self.glyph_size().width, |glyph| self.extent(glyph).width);
+                        ShapedGrapheme {
+                            grapheme,
+                            glyph,
+                            width,
+                            position: TextPosition::new(line_index, index),
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The size of a glyph of the font in pixels along and across a line, which is the width of
+    /// blank glyphs and the height of lines.
+    ///
+    /// Glyphs that are rotated a quarter turn are drawn in lines that are rotated with them, so
+    /// this is the same for every rotation.
+    fn glyph_size(&self) -> Size {
+        Size::new(self.font.width(), self.font.height())
+    }
+
+    /// The size of a glyph in pixels along and across the lines of text, as its width and height.
+    fn extent(&self, glyph: &Glyph) -> Size {
+        if self.rotation.is_quarter_turn() {
+            Size::new(glyph.height(), glyph.width())
+        } else {
+            Size::new(glyph.width(), glyph.height())
+        }
+    }
+
+    /// The number of pixels in a cell along and across the lines of text.
+    fn pixels_per_cell(&self, pixel_size: PixelSize) -> (u16, u16) {
+        let (step_x, step_y) = pixel_size.pixels_per_cell();
+        if self.rotation.is_quarter_turn() {
+            (step_y, step_x)
+        } else {
+            (step_x, step_y)
+        }
+    }
+
+    /// The sizes of glyphs and of the spaces between them for a pixel size.
+    ///
+    /// The glyphs of a proportional font that are rotated a quarter turn can be thicker than the
+    /// font is tall, so lines are as thick as the thickest glyph.
+    fn metrics(&self, lines: &[Vec<ShapedGrapheme<'_>>], pixel_size: PixelSize) -> Metrics {
+        let (step_x, step_y) = self.pixels_per_cell(pixel_size);
+        let height = lines
+            .iter()
+            .flatten()
+            .filter_map(|grapheme| grapheme.glyph.as_ref())
+            .map(|glyph| self.extent(glyph).height)
+            .fold(self.glyph_size().height, u16::max);
+        Metrics {
+            step_x,
+            letter_spacing: self.letter_spacing,
+            line_height: height.div_ceil(step_y),
+            line_pitch: height.saturating_add(self.line_spacing).div_ceil(step_y),
+        }
+    }
+
+    /// Splits the lines into the rows that are rendered, wrapping them to the given width if
+    /// wrapping is enabled.
+    fn rows<'s, 'g>(
+        &self,
+        lines: &'s [Vec<ShapedGrapheme<'g>>],
+        metrics: &Metrics,
+        width: u16,
+    ) -> Vec<&'s [ShapedGrapheme<'g>]> {
+        lines
+            .iter()
+            .flat_map(|line| {
+                if self.wrap {
+                    wrap(line, metrics, width)
+                } else {
+                    vec![line.as_slice()]
+                }
+            })
+            .collect()
+    }
+
+    /// The pixel size to render with, which is the largest pixel size that fits the area when
+    /// fitting is enabled.
+    fn fitted_pixel_size(&self, lines: &[Vec<ShapedGrapheme<'_>>], area: Rect) -> PixelSize {
+        if !self.fit {
+            return self.pixel_size;
+        }
+        let area = self.text_area(area);
+        PixelSize::LARGEST_FIRST
+            .into_iter()
+            .find(|&pixel_size| {
+                let size = self.measure(lines, pixel_size, area.width);
+                size.width <= area.width && size.height <= area.height
+            })
+            .unwrap_or(PixelSize::Octant)
+    }
+
+    /// The size of the rows of text along and across the lines when they are wrapped to the given
+    /// width.
+    fn measure(
+        &self,
+        lines: &[Vec<ShapedGrapheme<'_>>],
+        pixel_size: PixelSize,
+        width: u16,
+    ) -> Size {
+        let metrics = self.metrics(lines, pixel_size);
+        let rows = self.rows(lines, &metrics, width);
+        Size::new(
+            rows.iter()
+                .map(|row| metrics.row_width(row))
+                .max()
+                .unwrap_or(0),
+            metrics.height(rows.len()),
+        )
+    }
+
+    /// Chunk the area into cells for each grapheme of the rows that fit in the area, returned
+    /// along with the graphemes to render in them and the rows they are part of. The size of each
+    /// cell depends on the pixel size and the width of the glyph.
+    ///
+    /// The rows are laid out from left to right and top to bottom in the area of the text, and
+    /// then rotated into the area.
+    fn layout<'s, 'g>(
+        &self,
+        lines: &'s [Vec<ShapedGrapheme<'g>>],
+        pixel_size: PixelSize,
+        area: Rect,
+    ) -> Vec<Placement<'s, 'g>> {
+        let text_area = self.text_area(area);
+        self.layout_text(lines, pixel_size, text_area)
+            .into_iter()
+            .map(|placement| Placement {
+                cell: self.rotate_rect(placement.cell, area),
+                row: self.rotate_rect(placement.row, area),
+                ..placement
+            })
+            .collect()
+    }
+
+    /// The area that the rows of text are laid out in before they are rotated, which is the area
+    /// turned on its side when the lines run down or up the area.
+    fn text_area(&self, area: Rect) -> Rect {
+        if self.rotation.is_quarter_turn() {
+            Rect::new(0, 0, area.height, area.width)
+        } else {
+            area
+        }
+    }
+
+    /// Rotates a rectangle in the area of the text into the area, turning the lines of text a
+    /// quarter turn with their glyphs.
+    ///
+    /// Turned clockwise, the lines run down the area and follow each other from right to left.
+    /// Turned anticlockwise, they run up the area and follow each other from left to right.
+    fn rotate_rect(&self, rect: Rect, area: Rect) -> Rect {
+        match self.rotation {
+            Rotation::None | Rotation::Degrees180 => rect,
+            Rotation::Degrees90 => Rect::new(
+                area.right().saturating_sub(rect.bottom()),
+                area.y.saturating_add(rect.x),
+                rect.height,
+                rect.width,
+            ),
+            Rotation::Degrees270 => Rect::new(
+                area.x.saturating_add(rect.y),
+                area.bottom().saturating_sub(rect.right()),
+                rect.height,
+                rect.width,
+            ),
+        }
+    }
+
+    /// The alignment of the rows of text along and across the lines in the area of the text, which
+    /// is rotated into the alignment of the widget.
+    fn text_alignment(&self) -> (Alignment, VerticalAlignment) {
+        let horizontal = self.alignment;
+        let vertical = self.vertical_alignment;
+        match self.rotation {
+            Rotation::None | Rotation::Degrees180 => (horizontal, vertical),
+            Rotation::Degrees90 => (
+                match vertical {
+                    VerticalAlignment::Top => Alignment::Left,
+                    VerticalAlignment::Center => Alignment::Center,
+                    VerticalAlignment::Bottom => Alignment::Right,
+                },
+                match horizontal {
+                    Alignment::Left => VerticalAlignment::Bottom,
+                    Alignment::Center => VerticalAlignment::Center,
+                    Alignment::Right => VerticalAlignment::Top,
+                },
+            ),
+            Rotation::Degrees270 => (
+                match vertical {
+                    VerticalAlignment::Top => Alignment::Right,
+                    VerticalAlignment::Center => Alignment::Center,
+                    VerticalAlignment::Bottom => Alignment::Left,
+                },
+                match horizontal {
+                    Alignment::Left => VerticalAlignment::Top,
+                    Alignment::Center => VerticalAlignment::Center,
+                    Alignment::Right => VerticalAlignment::Bottom,
+                },
+            ),
+        }
+    }
+
+    /// Lays out the rows of text in the area of the text, before they are rotated.
+    fn layout_text<'s, 'g>(
+        &self,
+        lines: &'s [Vec<ShapedGrapheme<'g>>],
+        pixel_size: PixelSize,
+        area: Rect,
+    ) -> Vec<Placement<'s, 'g>> {
+        let (alignment, vertical_alignment) = self.text_alignment();
+        let metrics = self.metrics(lines, pixel_size);
+        let rows = self.rows(lines, &metrics, area.width);
+        let text_height = metrics.height(rows.len());
+        let top = area.top()
+            + get_vertical_alignment_offset(area.height, text_height, vertical_alignment);
+
+        // the top of each row, for as long as the rows fit in the area
+        let tops = (0..).map_while(|index: u16| {
+            let y = top.saturating_add(metrics.line_pitch.saturating_mul(index));
+            (y < area.bottom()).then_some(y)
+        });
+        tops.zip(rows)
+            .flat_map(|(y, row)| {
+                let row_width = metrics.row_width(row);
+                let offset = get_alignment_offset(area.width, row_width, alignment);
+                let mut x = area.left() + offset;
+                let row_area = Rect::new(x, y, row_width, metrics.line_height);
+                row.iter().enumerate().map_while(move |(index, grapheme)| {
+                    if x >= area.right() {
+                        return None;
+                    }
+                    let width = metrics.columns(grapheme, index + 1 == row.len());
+                    let cell = Rect::new(
+                        x,
+                        y,
+                        min(area.right() - x, width),
+                        min(area.bottom() - y, metrics.line_height),
+                    );
+                    x = x.saturating_add(width);
+                    Some(Placement {
+                        cell,
+                        row: row_area,
+                        grapheme,
+                    })
                 })
+            })
+            .collect()
+    }
+
+    /// The graphemes that fit in the area and the pixel size that they are rendered with, laid out
+    /// in pixels from the top left of the area.
+    ///
+    /// This is used by the animations, which move the pixels of the text independently of the
+    /// cells that they would be rendered in.
+    pub(crate) fn pixel_layout(&self, area: Rect) -> (PixelSize, Vec<PixelGrapheme>) {
+        let lines = self.shape();
+        let pixel_size = self.fitted_pixel_size(&lines, area);
+        let (step_x, step_y) = pixel_size.pixels_per_cell();
+        let graphemes = self
+            .layout(&lines, pixel_size, area)
+            .into_iter()
+            .map(|placement| PixelGrapheme {
+                x: i64::from(placement.cell.x - area.x) * i64::from(step_x),
+                y: i64::from(placement.cell.y - area.y) * i64::from(step_y),
+                width: i64::from(placement.cell.width) * i64::from(step_x),
+                height: i64::from(placement.cell.height) * i64::from(step_y),
+                style: placement.grapheme.grapheme.style,
+                glyph: placement.grapheme.glyph.clone(),
+            })
+            .collect();
+        (pixel_size, graphemes)
+    }
+
+    /// The glyph for a grapheme, or for the fallback character if the font has no glyph for it.
+    ///
+    /// Only the outline of the glyph is returned when outlines are enabled. The glyph is rotated
+    /// and mirrored as a whole, including the blank rows below it that make it as tall as the font.
+    fn glyph(&self, grapheme: &str) -> Option<Glyph> {
+        let glyph = grapheme::glyph(self.font, grapheme).or_else(|| {
+            if grapheme.chars().all(char::is_whitespace) {
+                return None;
+            }
+            let fallback = self.fallback?;
+            self.font.glyph(fallback.encode_utf8(&mut [0; 4]))
+        })?;
+        let glyph = if self.outline { glyph.outline() } else { glyph };
+        Some(
+            glyph
+                .padded(self.font.height())
+                .rotated(self.rotation)
+                .mirrored(self.mirror),
+        )
+    }
+
+    /// The style of a cell of a grapheme, which is the style of the grapheme colored by the
+    /// gradient, if there is one.
+    fn cell_style(&self, placement: &Placement<'_, '_>, position: Position) -> Style {
+        let style = placement.grapheme.grapheme.style;
+        self.gradient.map_or(style, |gradient| {
+            style.patch(gradient.style(placement.row, position))
         })
+    }
+}
+
+/// The rows of sub-characters of a line that is rendered with a FIGlet font, and their styles.
+type FigletLine = Vec<Vec<(char, Style)>>;
+
+impl BigText<'_> {
+    /// Renders each line with a FIGlet font.
+    fn figlet_lines(&self, font: &FigletFont) -> Vec<FigletLine> {
+        self.lines
+            .iter()
+            .map(|line| font.render_line(line.styled_graphemes(self.style)))
+            .collect()
+    }
+
+    /// The sizes of the lines of a FIGlet font, where each sub-character takes up a cell.
+    fn figlet_metrics(&self, font: &FigletFont) -> Metrics {
+        Metrics {
+            step_x: 1,
+            letter_spacing: 0,
+            line_height: font.height(),
+            line_pitch: font.height().saturating_add(self.line_spacing),
+        }
+    }
+
+    /// The size of the lines when they are rendered with a FIGlet font.
+    fn figlet_size(&self, font: &FigletFont, lines: &[FigletLine]) -> Size {
+        let metrics = self.figlet_metrics(font);
+        let width = lines.iter().map(figlet_line_width).max().unwrap_or(0);
+        Size::new(width, metrics.height(lines.len()))
+    }
+
+    /// Renders the text with a FIGlet font, a cell for each sub-character.
+    fn render_figlet(&self, font: &FigletFont, area: Rect, buf: &mut Buffer) {
+        let lines = self.figlet_lines(font);
+        let metrics = self.figlet_metrics(font);
+        let text_height = metrics.height(lines.len());
+        let top = area.top()
+            + get_vertical_alignment_offset(area.height, text_height, self.vertical_alignment);
+        for (index, line) in (0..).zip(&lines) {
+            let y = top.saturating_add(metrics.line_pitch.saturating_mul(index));
+            let width = figlet_line_width(line);
+            let x = area.left() + get_alignment_offset(area.width, width, self.alignment);
+            let row_area = Rect::new(x, y, width, metrics.line_height);
+            for (row, y) in line.iter().zip(y..area.bottom()) {
+                for (&(symbol, style), x) in row.iter().zip(x..area.right()) {
+                    let position = Position::new(x, y);
+                    let style = self.gradient.map_or(style, |gradient| {
+                        style.patch(gradient.style(row_area, position))
+                    });
+                    buf[position].set_char(symbol).set_style(style);
+                }
+            }
+        }
+    }
 }
 
-fn get_alignment_offset<'a>(
-    area_width: u16,
-    letter_width: u16,
-    alignment: Alignment,
-    line: &'a Line<'a>,
+/// The number of columns of a line that is rendered with a FIGlet font.
+fn figlet_line_width(line: &FigletLine) -> u16 {
+    let width = line.first().map_or(0, Vec::len);
+    u16::try_from(width).unwrap_or(u16::MAX)
+}
+
+/// Wraps a line at word boundaries so that each row fits in the given number of columns.
+///
+/// Words that are wider than a row are broken between graphemes. Whitespace at the end of a row
+/// is dropped so that it doesn't affect the alignment of the row.
+fn wrap<'s, 'g>(
+    line: &'s [ShapedGrapheme<'g>],
+    metrics: &Metrics,
+    width: u16,
+) -> Vec<&'s [ShapedGrapheme<'g>]> {
+    let trim_end = |row: &'s [ShapedGrapheme<'g>]| {
+        let end = row
+            .iter()
+            .rposition(|grapheme| !grapheme.is_whitespace())
+            .map_or(0, |index| index + 1);
+        &row[..end]
+    };
+    let mut rows = vec![];
+    let mut start = 0;
+    let mut used = 0u16;
+    let mut index = 0;
+    for word in line.chunk_by(|a, b| a.is_whitespace() == b.is_whitespace()) {
+        let word_start = index;
+        index += word.len();
+        if word[0].is_whitespace() {
+            for grapheme in word {
+                used = used.saturating_add(metrics.columns(grapheme, false));
+            }
+            continue;
+        }
+        if start < word_start && used.saturating_add(metrics.row_width(word)) > width {
+            rows.push(trim_end(&line[start..word_start]));
+            start = word_start;
+            used = 0;
+        }
+        for (offset, grapheme) in word.iter().enumerate() {
+            let last_columns = metrics.columns(grapheme, true);
+            if start < word_start + offset && used.saturating_add(last_columns) > width {
+                rows.push(&line[start..word_start + offset]);
+                start = word_start + offset;
+                used = 0;
+            }
+            used = used.saturating_add(metrics.columns(grapheme, false));
+        }
+    }
+    rows.push(trim_end(&line[start..]));
+    rows
+}
+
+/// Converts a row of a buffer into a line, with a span for each run of cells with the same style.
+///
+/// Blank cells at the end of the row are left out, unless they have a background color or a
+/// modifier that makes them visible.
+fn buffer_row(buf: &Buffer, y: u16) -> Line<'static> {
+    let mut spans: Vec<Span<'static>> = vec![];
+    for x in buf.area.left()..buf.area.right() {
+        let cell = &buf[(x, y)];
+        let style = visible_style(cell);
+        match spans.last_mut() {
+            Some(span) if span.style == style => span.content.to_mut().push_str(cell.symbol()),
+            _ => spans.push(Span::styled(cell.symbol().to_string(), style)),
+        }
+    }
+    while let Some(span) = spans.last_mut() {
+        if span.style.bg.is_some() || !span.style.add_modifier.is_empty() {
+            break;
+        }
+        let trimmed = span.content.trim_end_matches(' ').len();
+        span.content.to_mut().truncate(trimmed);
+        if !span.content.is_empty() {
+            break;
+        }
+        spans.pop();
+    }
+    Line::from(spans)
+}
+
+/// The style of a cell, without the colors that are reset to the default.
+fn visible_style(cell: &Cell) -> Style {
+    let mut style = Style::new().add_modifier(cell.modifier);
+    if cell.fg != Color::Reset {
+        style = style.fg(cell.fg);
+    }
+    if cell.bg != Color::Reset {
+        style = style.bg(cell.bg);
+    }
+    style
+}
+
+fn get_vertical_alignment_offset(
+    area_height: u16,
+    text_height: u16,
+    alignment: VerticalAlignment,
 ) -> u16 {
-    let big_line_width = line.width() as u16 * letter_width;
     match alignment {
-        Alignment::Center => (area_width / 2).saturating_sub(big_line_width / 2),
-        Alignment::Right => area_width.saturating_sub(big_line_width),
-        Alignment::Left => 0,
+        VerticalAlignment::Center => (area_height / 2).saturating_sub(text_height / 2),
+        VerticalAlignment::Bottom => area_height.saturating_sub(text_height),
+        VerticalAlignment::Top => 0,
     }
 }
 
-/// Render a single grapheme into a cell by looking up the corresponding 8x8 bitmap in the
-/// `BITMAPS` array and setting the corresponding cells in the buffer.
-fn render_symbol(grapheme: StyledGrapheme, area: Rect, buf: &mut Buffer, pixel_size: &PixelSize) {
-    buf.set_style(area, grapheme.style);
-    let c = grapheme.symbol.chars().next().unwrap(); // TODO: handle multi-char graphemes
-    if let Some(glyph) = font8x8::BASIC_FONTS.get(c) {
-        render_glyph(glyph, area, buf, pixel_size);
+fn get_alignment_offset(area_width: u16, big_line_width: u16, alignment: Alignment) -> u16 {
+    match alignment {
+        Alignment::Center => (area_width / 2).saturating_sub(big_line_width / 2),
+        Alignment::Right => area_width.saturating_sub(big_line_width),
+        Alignment::Left => 0,
     }
 }
 
-/// Render a single 8x8 glyph into a cell by setting the corresponding cells in the buffer.
-fn render_glyph(glyph: [u8; 8], area: Rect, buf: &mut Buffer, pixel_size: &PixelSize) {
+/// Render a single glyph into a cell by setting the corresponding cells in the buffer.
+///
+/// Blank cells are skipped, so that anything drawn behind the glyph shows through. The cells that
+/// are drawn are given the style returned by `style` for their position.
+fn render_glyph(
+    glyph: &Glyph,
+    area: Rect,
+    buf: &mut Buffer,
+    pixel_size: &PixelSize,
+    style: impl Fn(Position) -> Style,
+) {
     let (step_x, step_y) = pixel_size.pixels_per_cell();
 
-    let glyph_vertical_index = (0..glyph.len()).step_by(step_y as usize);
-    let glyph_horizontal_bit_selector = (0..8).step_by(step_x as usize);
+    let glyph_vertical_index = (0..glyph.height()).step_by(step_y as usize);
+    let glyph_horizontal_bit_selector = (0..glyph.width()).step_by(step_x as usize);
 
     for (y, row) in glyph_vertical_index.zip(area.rows()) {
         for (x, col) in glyph_horizontal_bit_selector.clone().zip(row.columns()) {
-            buf[col].set_char(pixel_size.symbol_for_position(&glyph, y, x));
+            let symbol = pixel_size.symbol_for_position(glyph, y, x);
+            if symbol != ' ' {
+                buf[col]
+                    .set_char(symbol)
+                    .set_style(style(col.as_position()));
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use ratatui_core::style::Stylize;
+    use colorgrad::{GradientBuilder, LinearGradient};
+    use ratatui_core::style::{Color, Stylize};
 
     use super::*;
 
@@ -219,6 +1087,19 @@ mod tests {
                 style,
                 pixel_size,
                 alignment,
+                vertical_alignment: VerticalAlignment::Top,
+                letter_spacing: 0,
+                line_spacing: 0,
+                font: &DEFAULT_FONT,
+                fallback: None,
+                wrap: false,
+                fit: false,
+                gradient: None,
+                shadow: None,
+                outline: false,
+                rotation: Rotation::None,
+                mirror: Mirror::None,
+                figlet_font: None,
             }
         );
     }
@@ -1131,4 +2012,587 @@ mod tests {
         ]);
         assert_eq!(buf, expected);
     }
+
+    /// A condensed font with proportional glyphs for `I` and `T`, and no other glyphs.
+    #[derive(Debug)]
+    struct CondensedFont;
+
+    impl Font for CondensedFont {
+        fn width(&self) -> u16 {
+            2
+        }
+
+        fn height(&self) -> u16 {
+            3
+        }
+
+        fn glyph(&self, grapheme: &str) -> Option<Glyph> {
+            match grapheme {
+                "I" => Some(Glyph::new(2, vec![0b01, 0b01, 0b01])),
+                "T" => Some(Glyph::new(4, vec![0b0111, 0b0010, 0b0010])),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn render_custom_font() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![Line::from("TI?I"), Line::from("IT")])
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "███ █   █ ",
+            " █  █   █ ",
+            " █  █   █ ",
+            "█ ███     ",
+            "█  █      ",
+            "█  █      ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_custom_font_alignment() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .pixel_size(PixelSize::HalfWidth)
+            .lines(vec![Line::from("TI")])
+            .right_aligned()
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec!["  █▌▌", "  ▐ ▌", "  ▐ ▌"]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_extended_characters() {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("éλ┼")])
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            " ▝▀ ▄    ▐  ",
+            "▟▀▙ ▝▙   ▐  ",
+            "█▀▀ ▗▛▙ ▀▜▀▀",
+            "▝▀▘ ▀ ▝▘ ▐  ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_fallback() {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .lines(vec![Line::from("Ж Ж")])
+            .fallback('?')
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "▟▀▙     ▟▀▙ ",
+            " ▗▛      ▗▛ ",
+            " ▀       ▀  ",
+            " ▀       ▀  ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_grapheme_clusters() {
+        let render = |text: &'static str| {
+            let big_text = BigText::builder()
+                .pixel_size(PixelSize::Quadrant)
+                .lines(vec![Line::from(text)])
+                .right_aligned()
+                .build();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+            big_text.render(buf.area, &mut buf);
+            buf
+        };
+        // the decomposed form takes up the same space as the precomposed one
+        assert_eq!(render("ée\u{301}"), render("éé"));
+        let expected = Buffer::with_lines(vec![
+            "    ▛▀▀▌▛▀▀▌",
+            "    ▌  ▌▌  ▌",
+            "    ▌  ▌▌  ▌",
+            "    ▀▀▀▘▀▀▀▘",
+        ]);
+        assert_eq!(render("😀\u{1F1F3}\u{1F1FF}"), expected);
+    }
+
+    #[test]
+    fn render_wrapped() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![Line::from("IT TI  T"), Line::from("ITT")])
+            .wrap(true)
+            .centered()
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 15));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "█ ███  ",
+            "█  █   ",
+            "█  █   ",
+            "███ █  ",
+            " █  █  ",
+            " █  █  ",
+            " ███   ",
+            "  █    ",
+            "  █    ",
+            "█ ███  ",
+            "█  █   ",
+            "█  █   ",
+            " ███   ",
+            "  █    ",
+            "  █    ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_fit() {
+        let big_text = BigText::builder()
+            .lines(vec![Line::from("Fit")])
+            .fit(true)
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        big_text.render(buf.area, &mut buf);
+        // only quadrant or smaller pixels fit the 24x8 pixels of the text into 12x4 cells
+        let expected = Buffer::with_lines(vec![
+            "▜▛▀▌ ▀   ▟  ",
+            "▐▙▌ ▝█  ▝█▀ ",
+            "▐▌▘  █   █▗ ",
+            "▀▀  ▝▀▘  ▝▘ ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn fit_with_wrapping() {
+        let big_text = BigText::builder()
+            .lines(vec![Line::from("ab cd")])
+            .wrap(true)
+            .fit(true)
+            .build();
+        let lines = big_text.shape();
+        // wrapped over two rows, "ab" and "cd" fit with half height pixels
+        let area = Rect::new(0, 0, 16, 8);
+        assert_eq!(
+            big_text.fitted_pixel_size(&lines, area),
+            PixelSize::HalfHeight
+        );
+        // with one row less, the rows only fit with third height pixels
+        let area = Rect::new(0, 0, 16, 7);
+        assert_eq!(
+            big_text.fitted_pixel_size(&lines, area),
+            PixelSize::ThirdHeight
+        );
+        let area = Rect::new(0, 0, 1, 1);
+        assert_eq!(big_text.fitted_pixel_size(&lines, area), PixelSize::Octant);
+    }
+
+    #[test]
+    fn size() {
+        let big_text = |pixel_size| {
+            BigText::builder()
+                .pixel_size(pixel_size)
+                .lines(vec![Line::from("Hello"), Line::from("World!")])
+                .build()
+        };
+        assert_eq!(big_text(PixelSize::Full).size(), Size::new(48, 16));
+        assert_eq!(big_text(PixelSize::HalfHeight).size(), Size::new(48, 8));
+        assert_eq!(big_text(PixelSize::Sextant).size(), Size::new(24, 6));
+        assert_eq!(big_text(PixelSize::Octant).width(), 24);
+        assert_eq!(big_text(PixelSize::Octant).height(), 4);
+        assert_eq!(BigText::builder().build().size(), Size::ZERO);
+    }
+
+    #[test]
+    fn size_with_proportional_glyphs() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .pixel_size(PixelSize::HalfWidth)
+            .lines(vec![Line::from("TI?I"), Line::from("IT")])
+            .build();
+        // 4 + 2 + 2 + 2 pixels wide, and 2 lines of 3 pixels
+        assert_eq!(big_text.size(), Size::new(5, 6));
+    }
+
+    #[test]
+    fn render_vertical_alignment() {
+        let render = |alignment| {
+            let big_text = BigText::builder()
+                .font(&CondensedFont)
+                .lines(vec![Line::from("IT")])
+                .vertical_alignment(alignment)
+                .build();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 6));
+            big_text.render(buf.area, &mut buf);
+            buf
+        };
+        let expected = Buffer::with_lines(vec![
+            "      ",
+            "      ",
+            "      ",
+            "█ ███ ",
+            "█  █  ",
+            "█  █  ",
+        ]);
+        assert_eq!(render(VerticalAlignment::Bottom), expected);
+        let expected = Buffer::with_lines(vec![
+            "      ",
+            "      ",
+            "█ ███ ",
+            "█  █  ",
+            "█  █  ",
+            "      ",
+        ]);
+        assert_eq!(render(VerticalAlignment::Center), expected);
+    }
+
+    #[test]
+    fn render_spacing() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![Line::from("TI"), Line::from("IT")])
+            .letter_spacing(1)
+            .line_spacing(2)
+            .right_aligned()
+            .build();
+        assert_eq!(big_text.size(), Size::new(7, 8));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            " ███  █ ",
+            "  █   █ ",
+            "  █   █ ",
+            "        ",
+            "        ",
+            " █  ███ ",
+            " █   █  ",
+            " █   █  ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_outline() {
+        let big_text = BigText::builder()
+            .lines(vec![Line::from("█")])
+            .outline(true)
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 8));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(vec![
+            "████████",
+            "█      █",
+            "█      █",
+            "█      █",
+            "█      █",
+            "█      █",
+            "█      █",
+            "████████",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_shadow() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![Line::from("I")])
+            .shadow(Shadow::new(1, 1, Color::Red))
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        big_text.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![
+            "█   ", //
+            "██  ", "██  ", " █  ",
+        ]);
+        expected.set_style(Rect::new(1, 1, 1, 3), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_gradient() {
+        let gradient = GradientBuilder::new()
+            .colors(&[
+                colorgrad::Color::new(0.0, 0.0, 0.0, 1.0),
+                colorgrad::Color::new(1.0, 1.0, 1.0, 1.0),
+            ])
+            .build::<LinearGradient>()
+            .unwrap();
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![Line::from("II")])
+            .gradient(TextGradient::horizontal(&gradient))
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        big_text.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![
+            "█ █ ", //
+            "█ █ ", "█ █ ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 1, 3), Style::new().fg(Color::Rgb(0, 0, 0)));
+        expected.set_style(
+            Rect::new(1, 0, 1, 3),
+            Style::new().fg(Color::Rgb(85, 85, 85)),
+        );
+        expected.set_style(
+            Rect::new(2, 0, 1, 3),
+            Style::new().fg(Color::Rgb(170, 170, 170)),
+        );
+        expected.set_style(
+            Rect::new(3, 0, 1, 3),
+            Style::new().fg(Color::Rgb(255, 255, 255)),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_background_gradient() {
+        let gradient = GradientBuilder::new()
+            .colors(&[
+                colorgrad::Color::new(0.0, 0.0, 0.0, 1.0),
+                colorgrad::Color::new(1.0, 1.0, 1.0, 1.0),
+            ])
+            .build::<LinearGradient>()
+            .unwrap();
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![Line::from("I")])
+            .gradient(TextGradient::vertical(&gradient).background())
+            .build();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 3));
+        big_text.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![
+            "█ ", //
+            "█ ", "█ ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().bg(Color::Rgb(0, 0, 0)));
+        expected.set_style(
+            Rect::new(0, 1, 2, 1),
+            Style::new().bg(Color::Rgb(128, 128, 128)),
+        );
+        expected.set_style(
+            Rect::new(0, 2, 2, 1),
+            Style::new().bg(Color::Rgb(255, 255, 255)),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_cursor() {
+        let render = |state: &mut BigTextState| {
+            let big_text = BigText::builder()
+                .font(&CondensedFont)
+                .lines(vec![Line::from("TI")])
+                .build();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+            widgets::StatefulWidget::render(big_text, buf.area, &mut buf, state);
+            buf
+        };
+        let lines = ["███ █   ", " █  █   ", " █  █   "];
+        let reversed = Style::new().reversed();
+
+        let mut state = BigTextState::new();
+        assert_eq!(render(&mut state), Buffer::with_lines(lines));
+
+        state.set_cursor(TextPosition::new(0, 1));
+        let mut expected = Buffer::with_lines(lines);
+        expected.set_style(Rect::new(4, 0, 2, 3), reversed);
+        assert_eq!(render(&mut state), expected);
+
+        // a cursor at the end of the line is drawn after the last glyph
+        state.set_cursor(TextPosition::new(0, 5));
+        let mut expected = Buffer::with_lines(lines);
+        expected.set_style(Rect::new(6, 0, 2, 3), reversed);
+        assert_eq!(render(&mut state), expected);
+
+        state.set_cursor(TextPosition::new(0, 0));
+        state.select_to(TextPosition::new(0, 2));
+        let mut expected = Buffer::with_lines(lines);
+        expected.set_style(Rect::new(0, 0, 8, 3), reversed);
+        assert_eq!(render(&mut state), expected);
+    }
+
+    #[test]
+    fn position_at() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![Line::from("TI"), Line::from("I")])
+            .centered()
+            .build();
+        let mut state = BigTextState::new();
+        assert_eq!(state.position_at(Position::new(3, 1)), None);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+        widgets::StatefulWidget::render(big_text, buf.area, &mut buf, &mut state);
+
+        let position_at = |x, y| state.position_at((x, y));
+        assert_eq!(position_at(0, 1), Some(TextPosition::new(0, 0)));
+        assert_eq!(position_at(3, 1), Some(TextPosition::new(0, 0)));
+        assert_eq!(position_at(6, 0), Some(TextPosition::new(0, 1)));
+        assert_eq!(position_at(9, 2), Some(TextPosition::new(0, 2)));
+        assert_eq!(position_at(4, 4), Some(TextPosition::new(1, 0)));
+        assert_eq!(position_at(6, 5), Some(TextPosition::new(1, 1)));
+        assert_eq!(position_at(4, 6), None);
+    }
+
+    #[test]
+    fn render_rotated() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .rotation(Rotation::Degrees90)
+            .lines(vec![Line::from("TI"), Line::from("I")])
+            .build();
+        // the lines run down the area, the first line on the right
+        assert_eq!(big_text.size(), Size::new(6, 6));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected =
+            Buffer::with_lines(["███  █", "   ███", "     █", "      ", "   ███", "      "]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_rotated_alignment() {
+        let render = |rotation| {
+            let big_text = BigText::builder()
+                .font(&CondensedFont)
+                .rotation(rotation)
+                .lines(vec![Line::from("I")])
+                .build();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            big_text.render(buf.area, &mut buf);
+            buf
+        };
+        // the text stays in the top left corner whichever way it runs
+        let expected = Buffer::with_lines(["███  ", "     ", "     "]);
+        assert_eq!(render(Rotation::Degrees90), expected);
+        let expected = Buffer::with_lines(["     ", "███  ", "     "]);
+        assert_eq!(render(Rotation::Degrees270), expected);
+    }
+
+    #[test]
+    fn render_rotated_anticlockwise() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .rotation(Rotation::Degrees270)
+            .lines(vec![Line::from("TI")])
+            .build();
+        // the line runs up the area, so the first glyph is at the bottom
+        assert_eq!(big_text.size(), Size::new(3, 6));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 6));
+        big_text.render(buf.area, &mut buf);
+        let expected = Buffer::with_lines(["   ", "███", "   ", "█  ", "███", "█  "]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_mirrored() {
+        let render = |rotation, mirror| {
+            let big_text = BigText::builder()
+                .font(&CondensedFont)
+                .rotation(rotation)
+                .mirror(mirror)
+                .lines(vec![Line::from("T")])
+                .build();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+            big_text.render(buf.area, &mut buf);
+            buf
+        };
+        let expected = Buffer::with_lines([" █  ", " █  ", "███ "]);
+        assert_eq!(render(Rotation::None, Mirror::Vertical), expected);
+        let expected = Buffer::with_lines([" ███", "  █ ", "  █ "]);
+        assert_eq!(render(Rotation::None, Mirror::Horizontal), expected);
+        assert_eq!(
+            render(Rotation::Degrees180, Mirror::Horizontal),
+            render(Rotation::None, Mirror::Vertical)
+        );
+    }
+
+    #[test]
+    fn render_figlet() {
+        let font = crate::figlet::tests::test_font();
+        let big_text = BigText::builder()
+            .figlet_font(&font)
+            .lines(vec![Line::from("|_|").red(), Line::from("o")])
+            .line_spacing(1)
+            .centered()
+            .build();
+        assert_eq!(big_text.size(), Size::new(5, 7));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 7));
+        big_text.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "   | |   ",
+            "   | |   ",
+            "   |_|   ",
+            "         ",
+            "         ",
+            "    o    ",
+            "         ",
+        ]);
+        expected.set_style(Rect::new(2, 0, 5, 3), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn to_lines() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![
+                Line::from(vec!["T".red(), "I".into()]),
+                Line::from("I"),
+            ])
+            .build();
+        assert_eq!(
+            big_text.to_lines(),
+            vec![
+                Line::from(vec!["███ ".red(), "█".into()]),
+                Line::from(vec![" █  ".red(), "█".into()]),
+                Line::from(vec![" █  ".red(), "█".into()]),
+                Line::from("█"),
+                Line::from("█"),
+                Line::from("█"),
+            ]
+        );
+    }
+
+    #[test]
+    fn display() {
+        let big_text = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .font(&CondensedFont)
+            .lines(vec![Line::from("TI"), Line::from("I").red()])
+            .alignment(Alignment::Right)
+            .build();
+        assert_eq!(big_text.to_string(), "▜▘▌\n▝ ▘\n  ▌\n  ▘");
+    }
+
+    #[test]
+    fn to_ansi_string() {
+        let big_text = BigText::builder()
+            .font(&CondensedFont)
+            .lines(vec![Line::from("IT").on_blue(), Line::from("I").red()])
+            .build();
+        assert_eq!(
+            big_text.to_ansi_string(),
+            [
+                "\x1b[44m█ ███ \x1b[0m",
+                "\x1b[44m█  █  \x1b[0m",
+                "\x1b[44m█  █  \x1b[0m",
+                "\x1b[31m█\x1b[0m",
+                "\x1b[31m█\x1b[0m",
+                "\x1b[31m█\x1b[0m",
+            ]
+            .join("\n")
+        );
+    }
 }
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::{error, fmt, fs, io};
+
+use crate::font::single_char;
+use crate::{bdf, psf, Font, Glyph};
+
+/// A bitmap font loaded from a font file at runtime.
+///
+/// Two formats are supported:
+///
+/// - [BDF], the Glyph Bitmap Distribution Format used by X11 fonts such as Terminus and Spleen.
+/// - [PSF] (versions 1 and 2), the format of Linux console fonts.
+///
+/// Glyphs can be up to 64 pixels wide. Characters that the font has no glyph for are rendered as
+/// blank space.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tui_big_text::{BigText, BitmapFont};
+///
+/// # fn main() -> Result<(), tui_big_text::FontError> {
+/// let font = BitmapFont::open("/usr/share/fonts/misc/spleen-8x16.bdf")?;
+/// let big_text = BigText::builder()
+///     .font(&font)
+///     .lines(vec!["Hello".into()])
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [BDF]: https://en.wikipedia.org/wiki/Glyph_Bitmap_Distribution_Format
+/// [PSF]: https://en.wikipedia.org/wiki/PC_Screen_Font
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BitmapFont {
+    width: u16,
+    height: u16,
+    glyphs: HashMap<char, Glyph>,
+}
+
+impl BitmapFont {
+    /// Loads a BDF or PSF font from a file.
+    ///
+    /// The format is detected from the contents of the file rather than its extension.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, FontError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    /// Parses a BDF or PSF font, detecting the format from the data.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, FontError> {
+        if psf::is_psf(data) {
+            Self::from_psf(data)
+        } else if bdf::is_bdf(data) {
+            Self::from_bdf(data)
+        } else {
+            Err(FontError::UnknownFormat)
+        }
+    }
+
+    /// Parses a font in the BDF format.
+    pub fn from_bdf(data: &[u8]) -> Result<Self, FontError> {
+        bdf::parse(&String::from_utf8_lossy(data))
+    }
+
+    /// Parses a font in the PSF format, version 1 or 2.
+    pub fn from_psf(data: &[u8]) -> Result<Self, FontError> {
+        psf::parse(data)
+    }
+
+    /// Creates an empty font with the given glyph width and line height.
+    pub(crate) fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            glyphs: HashMap::new(),
+        }
+    }
+
+    /// Adds the glyph for a character, unless the font already has one.
+    pub(crate) fn insert(&mut self, c: char, glyph: Glyph) {
+        self.glyphs.entry(c).or_insert(glyph);
+    }
+
+    /// The number of characters that the font has glyphs for.
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Returns `true` if the font has no glyphs.
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+}
+
+impl Font for BitmapFont {
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn height(&self) -> u16 {
+        self.height
+    }
+
+    fn glyph(&self, grapheme: &str) -> Option<Glyph> {
+        self.glyphs.get(&single_char(grapheme)?).cloned()
+    }
+}
+
+/// Converts a row of pixels stored most significant bit first, as in BDF and PSF fonts, into a row
+/// of a [`Glyph`].
+pub(crate) fn glyph_row(bytes: &[u8], width: u16) -> u64 {
+    (0..width.min(64))
+        .filter(|&x| {
+            let byte = bytes.get(usize::from(x / 8)).copied().unwrap_or_default();
+            byte & (0x80 >> (x % 8)) != 0
+        })
+        .fold(0, |row, x| row | 1 << x)
+}
+
+/// An error that occurs when loading a [`BitmapFont`] or a [`FigletFont`](crate::FigletFont).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FontError {
+    /// The font file could not be read.
+    Io(io::Error),
+    /// The data is not in a supported font format.
+    UnknownFormat,
+    /// The BDF data is malformed.
+    InvalidBdf {
+        /// The line of the file that the error was found on, starting at 1.
+        line: usize,
+        /// What is wrong with the line.
+        reason: &'static str,
+    },
+    /// The PSF data is malformed.
+    InvalidPsf(&'static str),
+    /// The FIGlet data is malformed.
+    InvalidFiglet {
+        /// The line of the file that the error was found on, starting at 1.
+        line: usize,
+        /// What is wrong with the line.
+        reason: &'static str,
+    },
+    /// The font has glyphs that are wider than the 64 pixels that a [`Glyph`] can hold.
+    GlyphTooWide(u32),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read font: {err}"),
+            Self::UnknownFormat => f.write_str("unknown font format"),
+            Self::InvalidBdf { line, reason } => {
+                write!(f, "invalid BDF font at line {line}: {reason}")
+            }
+            Self::InvalidPsf(reason) => write!(f, "invalid PSF font: {reason}"),
+            Self::InvalidFiglet { line, reason } => {
+                write!(f, "invalid FIGlet font at line {line}: {reason}")
+            }
+            Self::GlyphTooWide(width) => {
+                write!(
+                    f,
+                    "glyphs are {width} pixels wide, but at most 64 are supported"
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for FontError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FontError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_row_reverses_bits() {
+        assert_eq!(glyph_row(&[0b1000_0001], 8), 0b1000_0001);
+        assert_eq!(glyph_row(&[0b1100_0000], 8), 0b0000_0011);
+        assert_eq!(glyph_row(&[0b0000_0000, 0b1000_0000], 9), 1 << 8);
+        assert_eq!(glyph_row(&[0b1111_1111], 3), 0b111);
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert!(matches!(
+            BitmapFont::from_bytes(b"not a font"),
+            Err(FontError::UnknownFormat)
+        ));
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = BitmapFont::open("does/not/exist.bdf").unwrap_err();
+        assert!(matches!(err, FontError::Io(_)));
+        assert!(error::Error::source(&err).is_some());
+    }
+}
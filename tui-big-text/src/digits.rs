@@ -0,0 +1,606 @@
+//! Built-in fonts for numbers, such as the readouts of clocks, counters and gauges.
+//!
+//! Each font covers the digits `0` to `9`, the punctuation `:`, `.`, `-` and `%`, and the units
+//! `°`, `C`, `F`, `h`, `s` and `A`, so that times, temperatures, percentages and currents can be
+//! shown.
+
+use crate::font::single_char;
+use crate::{Font, Glyph};
+
+/// A seven-segment display font, like the display of a digital clock.
+///
+/// Glyphs are drawn with thin segments that join at the corners. Digits are 6 pixels wide,
+/// including a blank column between them, and lines are 8 pixels tall, so a digit takes up 6x8
+/// cells at [`PixelSize::Full`](crate::PixelSize::Full).
+///
+/// It covers the digits `0` to `9`, the punctuation `:`, `.`, `-` and `%`, and the units `°`,
+/// `C`, `F`, `h`, `s` and `A`, so that times, temperatures, percentages and currents can be shown.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_big_text::{BigText, SevenSegment};
+///
+/// let big_text = BigText::builder()
+///     .font(&SevenSegment)
+///     .lines(vec!["12:34".into()])
+///     .build();
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SevenSegment;
+
+/// A segmented LCD font, like the display of a calculator or a multimeter.
+///
+/// Glyphs are drawn with thick segments that are separated by a gap at the corners. Digits are 9
+/// pixels wide, including a blank column between them, and lines are 13 pixels tall.
+///
+/// It covers the same characters as [`SevenSegment`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SegmentedLcd;
+
+/// A font of smooth, rounded digits.
+///
+/// Glyphs are hand-drawn with strokes that are two pixels wide and one pixel tall, which look
+/// about as thick as each other in a terminal, as cells are about twice as tall as they are wide.
+/// Digits are 8 pixels wide, including a blank column between them, and lines are 10 pixels tall.
+///
+/// It covers the same characters as [`SevenSegment`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RoundedDigits;
+
+impl Font for SevenSegment {
+    fn width(&self) -> u16 {
+        SEVEN_SEGMENT.width() + 1
+    }
+
+    fn height(&self) -> u16 {
+        SEVEN_SEGMENT.height() + 1
+    }
+
+    fn glyph(&self, grapheme: &str) -> Option<Glyph> {
+        SEVEN_SEGMENT.glyph(single_char(grapheme)?)
+    }
+}
+
+impl Font for SegmentedLcd {
+    fn width(&self) -> u16 {
+        SEGMENTED_LCD.width() + 1
+    }
+
+    fn height(&self) -> u16 {
+        SEGMENTED_LCD.height() + 1
+    }
+
+    fn glyph(&self, grapheme: &str) -> Option<Glyph> {
+        SEGMENTED_LCD.glyph(single_char(grapheme)?)
+    }
+}
+
+impl Font for RoundedDigits {
+    fn width(&self) -> u16 {
+        8
+    }
+
+    fn height(&self) -> u16 {
+        10
+    }
+
+    fn glyph(&self, grapheme: &str) -> Option<Glyph> {
+        let c = single_char(grapheme)?;
+        let (_, art) = ROUNDED_DIGITS.iter().find(|(glyph, _)| *glyph == c)?;
+        Some(from_art(art))
+    }
+}
+
+// The segments of a seven-segment display, as bits of a `u8`.
+const TOP: u8 = 1 << 0;
+const TOP_RIGHT: u8 = 1 << 1;
+const BOTTOM_RIGHT: u8 = 1 << 2;
+const BOTTOM: u8 = 1 << 3;
+const BOTTOM_LEFT: u8 = 1 << 4;
+const TOP_LEFT: u8 = 1 << 5;
+const MIDDLE: u8 = 1 << 6;
+
+/// The segments that are lit for each character that is drawn only with segments.
+#[rustfmt::skip]
+const SEGMENTS: [(char, u8); 17] = [
+    ('0', TOP | TOP_RIGHT | BOTTOM_RIGHT | BOTTOM | BOTTOM_LEFT | TOP_LEFT),
+    ('1', TOP_RIGHT | BOTTOM_RIGHT),
+    ('2', TOP | TOP_RIGHT | MIDDLE | BOTTOM_LEFT | BOTTOM),
+    ('3', TOP | TOP_RIGHT | MIDDLE | BOTTOM_RIGHT | BOTTOM),
+    ('4', TOP_LEFT | TOP_RIGHT | MIDDLE | BOTTOM_RIGHT),
+    ('5', TOP | TOP_LEFT | MIDDLE | BOTTOM_RIGHT | BOTTOM),
+    ('6', TOP | TOP_LEFT | MIDDLE | BOTTOM_LEFT | BOTTOM_RIGHT | BOTTOM),
+    ('7', TOP | TOP_RIGHT | BOTTOM_RIGHT),
+    ('8', TOP | TOP_RIGHT | BOTTOM_RIGHT | BOTTOM | BOTTOM_LEFT | TOP_LEFT | MIDDLE),
+    ('9', TOP | TOP_LEFT | TOP_RIGHT | MIDDLE | BOTTOM_RIGHT | BOTTOM),
+    ('-', MIDDLE),
+    ('°', TOP | TOP_LEFT | TOP_RIGHT | MIDDLE),
+    ('C', TOP | TOP_LEFT | BOTTOM_LEFT | BOTTOM),
+    ('F', TOP | TOP_LEFT | MIDDLE | BOTTOM_LEFT),
+    ('h', TOP_LEFT | MIDDLE | BOTTOM_LEFT | BOTTOM_RIGHT),
+    ('s', TOP | TOP_LEFT | MIDDLE | BOTTOM_RIGHT | BOTTOM),
+    ('A', TOP | TOP_LEFT | TOP_RIGHT | MIDDLE | BOTTOM_LEFT | BOTTOM_RIGHT),
+];
+
+/// The dimensions of the segments of a segment display font, in pixels.
+#[derive(Debug, Clone, Copy)]
+struct SegmentShape {
+    /// The thickness of each segment.
+    thickness: u16,
+    /// The length of the horizontal segments, between the vertical segments.
+    across: u16,
+    /// The length of the vertical segments, between the horizontal segments.
+    down: u16,
+    /// Whether the segments are joined at the corners rather than separated by a gap.
+    joined: bool,
+}
+
+const SEVEN_SEGMENT: SegmentShape = SegmentShape {
+    thickness: 1,
+    across: 3,
+    down: 2,
+    joined: true,
+};
+
+const SEGMENTED_LCD: SegmentShape = SegmentShape {
+    thickness: 2,
+    across: 4,
+    down: 3,
+    joined: false,
+};
+
+impl SegmentShape {
+    /// The width of a digit, not including the blank column after it.
+    const fn width(self) -> u16 {
+        2 * self.thickness + self.across
+    }
+
+    /// The height of a digit, not including the blank row below it.
+    const fn height(self) -> u16 {
+        3 * self.thickness + 2 * self.down
+    }
+
+    fn glyph(self, c: char) -> Option<Glyph> {
+        let (t, width, height) = (self.thickness, self.width(), self.height());
+        let mut canvas = Canvas::new(width, height);
+        match c {
+            '.' => {
+                canvas = Canvas::new(t, height);
+                canvas.fill(0, height - t, t, t);
+            }
+            ':' => {
+                canvas = Canvas::new(t, height);
+                // a dot in the middle of each of the vertical segments
+                let offset = self.down.saturating_sub(t) / 2;
+                canvas.fill(0, t + offset, t, t);
+                canvas.fill(0, 2 * t + self.down + offset, t, t);
+            }
+            '%' => {
+                canvas.fill(0, 0, t, t);
+                canvas.fill(width - t, height - t, t, t);
+                // a slash from the bottom left to the top right
+                for y in 0..height {
+                    let x = (height - 1 - y) * (width - t) / (height - 1);
+                    canvas.fill(x, y, t, 1);
+                }
+            }
+            c => {
+                let (_, segments) = SEGMENTS.iter().find(|(glyph, _)| *glyph == c)?;
+                self.draw_segments(&mut canvas, *segments);
+            }
+        }
+        Some(canvas.into_glyph())
+    }
+
+    fn draw_segments(self, canvas: &mut Canvas, segments: u8) {
+        let (t, across, down) = (self.thickness, self.across, self.down);
+        let right = t + across;
+        let middle = t + down;
+        let bottom = middle + t + down;
+        // joined segments overlap the corners that they meet at
+        let (x, width) = if self.joined {
+            (0, self.width())
+        } else {
+            (t, across)
+        };
+        let (extend, length) = if self.joined {
+            (t, down + 2 * t)
+        } else {
+            (0, down)
+        };
+        let lit = |segment| segments & segment != 0;
+        if lit(TOP) {
+            canvas.fill(x, 0, width, t);
+        }
+        if lit(MIDDLE) {
+            canvas.fill(x, middle, width, t);
+        }
+        if lit(BOTTOM) {
+            canvas.fill(x, bottom, width, t);
+        }
+        if lit(TOP_LEFT) {
+            canvas.fill(0, t - extend, t, length);
+        }
+        if lit(TOP_RIGHT) {
+            canvas.fill(right, t - extend, t, length);
+        }
+        if lit(BOTTOM_LEFT) {
+            canvas.fill(0, middle + t - extend, t, length);
+        }
+        if lit(BOTTOM_RIGHT) {
+            canvas.fill(right, middle + t - extend, t, length);
+        }
+    }
+}
+
+/// The pixels of a glyph that is being drawn.
+struct Canvas {
+    width: u16,
+    rows: Vec<u64>,
+}
+
+impl Canvas {
+    fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            rows: vec![0; usize::from(height)],
+        }
+    }
+
+    /// Sets the pixels of a rectangle.
+    fn fill(&mut self, x: u16, y: u16, width: u16, height: u16) {
+        let bits = (x..x + width).fold(0, |row, x| row | 1 << x);
+        for row in self
+            .rows
+            .iter_mut()
+            .skip(usize::from(y))
+            .take(usize::from(height))
+        {
+            *row |= bits;
+        }
+    }
+
+    /// The glyph of the canvas, with a blank column and row after it as space between glyphs.
+    fn into_glyph(mut self) -> Glyph {
+        self.rows.push(0);
+        Glyph::new(self.width + 1, self.rows)
+    }
+}
+
+/// Creates a glyph from rows of text, where `#` is a set pixel, with a blank column after it.
+fn from_art(art: &[&str]) -> Glyph {
+    let width = art.iter().map(|row| row.len()).max().unwrap_or(0);
+    let rows = art.iter().map(|row| {
+        row.bytes()
+            .enumerate()
+            .filter(|(_, pixel)| *pixel == b'#')
+            .fold(0, |row, (x, _)| row | 1 << x)
+    });
+    Glyph::new(
+        u16::try_from(width + 1).unwrap_or(u16::MAX),
+        rows.collect::<Vec<_>>(),
+    )
+}
+
+/// The glyphs of [`RoundedDigits`], drawn as 9 rows of text with a blank row after them.
+#[rustfmt::skip]
+const ROUNDED_DIGITS: [(char, [&str; 10]); 20] = [
+    ('0', [
+        " ##### ",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        " ##### ",
+        "",
+    ]),
+    ('1', [
+        "  ###  ",
+        " ####  ",
+        "   ##  ",
+        "   ##  ",
+        "   ##  ",
+        "   ##  ",
+        "   ##  ",
+        "   ##  ",
+        " ######",
+        "",
+    ]),
+    ('2', [
+        " ##### ",
+        "##   ##",
+        "     ##",
+        "    ## ",
+        "   ##  ",
+        "  ##   ",
+        " ##    ",
+        "##     ",
+        "#######",
+        "",
+    ]),
+    ('3', [
+        " ##### ",
+        "##   ##",
+        "     ##",
+        "     ##",
+        "  #### ",
+        "     ##",
+        "     ##",
+        "##   ##",
+        " ##### ",
+        "",
+    ]),
+    ('4', [
+        "    ## ",
+        "   ### ",
+        "  #### ",
+        " ## ## ",
+        "##  ## ",
+        "#######",
+        "    ## ",
+        "    ## ",
+        "    ## ",
+        "",
+    ]),
+    ('5', [
+        "#######",
+        "##     ",
+        "##     ",
+        "###### ",
+        "     ##",
+        "     ##",
+        "     ##",
+        "##   ##",
+        " ##### ",
+        "",
+    ]),
+    ('6', [
+        "  #### ",
+        " ##    ",
+        "##     ",
+        "###### ",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        " ##### ",
+        "",
+    ]),
+    ('7', [
+        "#######",
+        "     ##",
+        "    ## ",
+        "    ## ",
+        "   ##  ",
+        "   ##  ",
+        "  ##   ",
+        "  ##   ",
+        "  ##   ",
+        "",
+    ]),
+    ('8', [
+        " ##### ",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        " ##### ",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        " ##### ",
+        "",
+    ]),
+    ('9', [
+        " ##### ",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        " ######",
+        "     ##",
+        "     ##",
+        "    ## ",
+        " ####  ",
+        "",
+    ]),
+    (':', [
+        "",
+        "",
+        "##",
+        "##",
+        "",
+        "",
+        "##",
+        "##",
+        "",
+        "",
+    ]),
+    ('.', [
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "##",
+        "##",
+        "",
+    ]),
+    ('-', [
+        "",
+        "",
+        "",
+        "",
+        " ##### ",
+        "",
+        "",
+        "",
+        "",
+        "",
+    ]),
+    ('%', [
+        "##   ##",
+        "##  ## ",
+        "    ## ",
+        "   ##  ",
+        "   ##  ",
+        "  ##   ",
+        "  ##   ",
+        " ##  ##",
+        "##   ##",
+        "",
+    ]),
+    ('°', [
+        " ### ",
+        "## ##",
+        " ### ",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+    ]),
+    ('C', [
+        " ##### ",
+        "##   ##",
+        "##     ",
+        "##     ",
+        "##     ",
+        "##     ",
+        "##     ",
+        "##   ##",
+        " ##### ",
+        "",
+    ]),
+    ('F', [
+        "#######",
+        "##     ",
+        "##     ",
+        "##     ",
+        "###### ",
+        "##     ",
+        "##     ",
+        "##     ",
+        "##     ",
+        "",
+    ]),
+    ('h', [
+        "##     ",
+        "##     ",
+        "##     ",
+        "###### ",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "",
+    ]),
+    ('s', [
+        "",
+        "",
+        "",
+        " ######",
+        "##     ",
+        " ##### ",
+        "     ##",
+        "     ##",
+        "###### ",
+        "",
+    ]),
+    ('A', [
+        "  ###  ",
+        " ## ## ",
+        "##   ##",
+        "##   ##",
+        "#######",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "##   ##",
+        "",
+    ]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The pixels of a glyph as rows of text, where `#` is a set pixel.
+    fn art(glyph: &Glyph) -> Vec<String> {
+        (0..glyph.height())
+            .map(|y| {
+                (0..glyph.width())
+                    .map(|x| if glyph.pixel(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn coverage() {
+        let fonts: [&dyn Font; 3] = [&SevenSegment, &SegmentedLcd, &RoundedDigits];
+        for font in fonts {
+            for c in "0123456789:.-%°CFhsA".chars() {
+                let glyph = font.glyph(&c.to_string());
+                assert!(glyph.is_some(), "{font:?} has no glyph for {c}");
+                assert!(glyph.unwrap().width() <= font.width(), "{font:?} {c}");
+            }
+            assert_eq!(font.glyph("x"), None);
+        }
+    }
+
+    #[test]
+    fn seven_segment() {
+        let glyph = SevenSegment.glyph("4").unwrap();
+        assert_eq!(
+            art(&glyph),
+            [
+                "#...#.", //
+                "#...#.", "#...#.", "#####.", "....#.", "....#.", "....#.", "......",
+            ]
+        );
+        let glyph = SevenSegment.glyph(":").unwrap();
+        assert_eq!(
+            art(&glyph),
+            ["..", "#.", "..", "..", "#.", "..", "..", ".."]
+        );
+    }
+
+    #[test]
+    fn segmented_lcd() {
+        let glyph = SegmentedLcd.glyph("7").unwrap();
+        assert_eq!(
+            art(&glyph),
+            [
+                "..####...",
+                "..####...",
+                "......##.",
+                "......##.",
+                "......##.",
+                ".........",
+                ".........",
+                "......##.",
+                "......##.",
+                "......##.",
+                ".........",
+                ".........",
+                ".........",
+            ]
+        );
+    }
+
+    #[test]
+    fn rounded_digits() {
+        let glyph = RoundedDigits.glyph("0").unwrap();
+        assert_eq!((glyph.width(), glyph.height()), (8, 10));
+        assert_eq!(art(&glyph)[..2], [".#####..", "##...##."]);
+        assert_eq!(RoundedDigits.glyph(".").unwrap().width(), 3);
+    }
+}
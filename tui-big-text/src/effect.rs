@@ -0,0 +1,201 @@
+use std::hash::{Hash, Hasher};
+use std::{fmt, ptr};
+
+use colorgrad::Gradient;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::{Color, Style};
+
+/// A [colorgrad] gradient that colors the cells of [`BigText`](crate::BigText).
+///
+/// A vertical gradient runs from the top to the bottom of each line of text, and a horizontal
+/// gradient from the left to the right of each line. The gradient colors the foreground of the
+/// glyphs, or the background of the cells that they are drawn in.
+///
+/// The color is picked for each cell rather than each pixel, as a terminal cell has a single
+/// foreground and background color. Gradients are compared by identity.
+///
+/// # Example
+///
+/// ```rust
+/// use colorgrad::{Color, GradientBuilder, LinearGradient};
+/// use tui_big_text::{BigText, TextGradient};
+///
+/// let gradient = GradientBuilder::new()
+///     .colors(&[
+///         Color::new(1.0, 0.0, 0.0, 1.0),
+///         Color::new(0.0, 0.0, 1.0, 1.0),
+///     ])
+///     .build::<LinearGradient>()
+///     .unwrap();
+/// let big_text = BigText::builder()
+///     .gradient(TextGradient::vertical(&gradient))
+///     .lines(vec!["Hello".into()])
+///     .build();
+/// ```
+///
+/// [colorgrad]: https://crates.io/crates/colorgrad
+#[derive(Clone, Copy)]
+pub struct TextGradient<'a> {
+    gradient: &'a dyn Gradient,
+    direction: GradientDirection,
+    background: bool,
+}
+
+/// The direction that a [`TextGradient`] runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum GradientDirection {
+    Vertical,
+    Horizontal,
+}
+
+impl<'a> TextGradient<'a> {
+    /// A gradient that runs from the top to the bottom of each line.
+    pub fn vertical(gradient: &'a dyn Gradient) -> Self {
+        Self {
+            gradient,
+            direction: GradientDirection::Vertical,
+            background: false,
+        }
+    }
+
+    /// A gradient that runs from the left to the right of each line.
+    pub fn horizontal(gradient: &'a dyn Gradient) -> Self {
+        Self {
+            gradient,
+            direction: GradientDirection::Horizontal,
+            background: false,
+        }
+    }
+
+    /// Colors the background of the cells of each glyph instead of the foreground.
+    #[must_use]
+    pub const fn background(mut self) -> Self {
+        self.background = true;
+        self
+    }
+
+    /// The style for a cell of a line of text, which patches the color of the cell.
+    pub(crate) fn style(&self, line: Rect, position: Position) -> Style {
+        let fraction = |offset: u16, length: u16| {
+            if length <= 1 {
+                0.0
+            } else {
+                f32::from(offset) / f32::from(length - 1)
+            }
+        };
+        let value = match self.direction {
+            GradientDirection::Vertical => fraction(position.y - line.y, line.height),
+            GradientDirection::Horizontal => fraction(position.x - line.x, line.width),
+        };
+        let [r, g, b, _] = self.gradient.at(value.clamp(0.0, 1.0)).to_rgba8();
+        let color = Color::Rgb(r, g, b);
+        if self.background {
+            Style::new().bg(color)
+        } else {
+            Style::new().fg(color)
+        }
+    }
+}
+
+impl fmt::Debug for TextGradient<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextGradient")
+            .field("direction", &self.direction)
+            .field("background", &self.background)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for TextGradient<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::addr_eq(self.gradient, other.gradient)
+            && self.direction == other.direction
+            && self.background == other.background
+    }
+}
+
+impl Eq for TextGradient<'_> {}
+
+impl Hash for TextGradient<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ptr::from_ref(self.gradient).cast::<()>().hash(state);
+        self.direction.hash(state);
+        self.background.hash(state);
+    }
+}
+
+/// A drop shadow that is drawn behind the glyphs of [`BigText`](crate::BigText).
+///
+/// The shadow is a copy of each glyph, moved right and down by the offset in pixels and drawn in
+/// the shadow color. Where a cell holds pixels of both a glyph and a shadow, the glyph is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shadow {
+    /// The number of pixels that the shadow is moved right by.
+    pub x: u16,
+    /// The number of pixels that the shadow is moved down by.
+    pub y: u16,
+    /// The color of the shadow.
+    pub color: Color,
+}
+
+impl Shadow {
+    /// Creates a shadow that is moved right and down by the given number of pixels.
+    pub const fn new(x: u16, y: u16, color: Color) -> Self {
+        Self { x, y, color }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use colorgrad::{GradientBuilder, LinearGradient};
+
+    use super::*;
+
+    fn black_to_white() -> LinearGradient {
+        GradientBuilder::new()
+            .colors(&[
+                colorgrad::Color::new(0.0, 0.0, 0.0, 1.0),
+                colorgrad::Color::new(1.0, 1.0, 1.0, 1.0),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn gradient_style() {
+        let gradient = black_to_white();
+        let line = Rect::new(2, 2, 5, 3);
+        let vertical = TextGradient::vertical(&gradient);
+        assert_eq!(
+            vertical.style(line, Position::new(6, 2)),
+            Style::new().fg(Color::Rgb(0, 0, 0))
+        );
+        assert_eq!(
+            vertical.style(line, Position::new(2, 4)),
+            Style::new().fg(Color::Rgb(255, 255, 255))
+        );
+        let horizontal = TextGradient::horizontal(&gradient).background();
+        assert_eq!(
+            horizontal.style(line, Position::new(6, 2)),
+            Style::new().bg(Color::Rgb(255, 255, 255))
+        );
+    }
+
+    #[test]
+    fn gradients_are_compared_by_identity() {
+        let gradient = black_to_white();
+        let other = black_to_white();
+        assert_eq!(
+            TextGradient::vertical(&gradient),
+            TextGradient::vertical(&gradient)
+        );
+        assert_ne!(
+            TextGradient::vertical(&gradient),
+            TextGradient::vertical(&other)
+        );
+        assert_ne!(
+            TextGradient::vertical(&gradient),
+            TextGradient::horizontal(&gradient)
+        );
+    }
+}
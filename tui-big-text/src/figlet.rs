@@ -0,0 +1,583 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use ratatui_core::style::Style;
+use ratatui_core::text::StyledGrapheme;
+
+use crate::font::single_char;
+use crate::FontError;
+
+/// The bits of the layout of a FIGlet font that enable each horizontal smushing rule, and
+/// kerning and smushing themselves.
+const EQUAL: u32 = 1;
+const LOWLINE: u32 = 2;
+const HIERARCHY: u32 = 4;
+const PAIR: u32 = 8;
+const BIG_X: u32 = 16;
+const HARDBLANK: u32 = 32;
+const RULES: u32 = 63;
+const KERNING: u32 = 64;
+const SMUSHING: u32 = 128;
+
+/// The characters that follow the printable ASCII characters in every FIGlet font.
+const DEUTSCH: [char; 7] = ['Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', 'ß'];
+
+/// The classes of the hierarchy smushing rule, from the lowest to the highest.
+const HIERARCHY_CLASSES: [&str; 6] = ["|", "/\\", "[]", "{}", "()", "<>"];
+
+/// A [FIGlet] font, which draws each character with several lines of other characters.
+///
+/// FIGlet fonts are an alternative to bitmap [`Font`](crate::Font)s. They are rendered one
+/// terminal cell per sub-character rather than with a [`PixelSize`](crate::PixelSize), and the
+/// characters of a line are moved together until they touch (kerning) or overlap (smushing),
+/// following the layout rules of the font. Use [`BigText::figlet_font`] to render text with one.
+///
+/// Characters that the font has no art for are skipped, as FIGlet does. Fonts that are compressed
+/// into a zip file must be extracted first.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tui_big_text::{BigText, FigletFont};
+///
+/// # fn main() -> Result<(), tui_big_text::FontError> {
+/// let font = FigletFont::open("/usr/share/figlet/standard.flf")?;
+/// let big_text = BigText::builder()
+///     .figlet_font(&font)
+///     .lines(vec!["Hello".into()])
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [FIGlet]: http://www.figlet.org/
+/// [`BigText::figlet_font`]: crate::BigText::figlet_font
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct FigletFont {
+    height: u16,
+    /// The sub-character that is drawn as a space, but is not removed by kerning or smushing.
+    hardblank: char,
+    layout: u32,
+    /// The art of each character, with rows of the same width.
+    chars: BTreeMap<char, Vec<Vec<char>>>,
+}
+
+impl FigletFont {
+    /// Loads a FIGlet font from a `.flf` file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, FontError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    /// Parses a FIGlet font.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, FontError> {
+        parse(&String::from_utf8_lossy(data))
+    }
+
+    /// The number of rows of each character.
+    pub const fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// The number of characters that the font has art for.
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Returns `true` if the font has no characters.
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Renders the graphemes of a line into rows of sub-characters, each with the style of the
+    /// grapheme that it was drawn for.
+    ///
+    /// Each character is kerned or smushed into the characters before it according to the layout
+    /// of the font. Where two sub-characters are smushed into one, it keeps the style of the
+    /// sub-character that it came from, or takes the style of the right one if it is a new
+    /// sub-character. Graphemes that the font has no art for are skipped.
+    pub(crate) fn render_line<'a>(
+        &self,
+        graphemes: impl IntoIterator<Item = StyledGrapheme<'a>>,
+    ) -> Vec<Vec<(char, Style)>> {
+        let mut rows = vec![Vec::new(); usize::from(self.height)];
+        let mut previous_width = 0;
+        for grapheme in graphemes {
+            let Some(art) = single_char(grapheme.symbol).and_then(|c| self.chars.get(&c)) else {
+                continue;
+            };
+            let width = art.first().map_or(0, Vec::len);
+            let overlap = self.overlap(&rows, art, previous_width);
+            for (row, art_row) in rows.iter_mut().zip(art) {
+                let start = row.len() - overlap;
+                for (index, &right) in art_row.iter().enumerate() {
+                    let Some((left, left_style)) = row.get(start + index).copied() else {
+                        row.push((right, grapheme.style));
+                        continue;
+                    };
+                    if right != ' ' {
+                        let symbol = self
+                            .smush(left, right, previous_width, width)
+                            .unwrap_or(right);
+                        let style = if symbol == left && symbol != right {
+                            left_style
+                        } else {
+                            grapheme.style
+                        };
+                        row[start + index] = (symbol, style);
+                    }
+                }
+            }
+            previous_width = width;
+        }
+        for (symbol, _) in rows.iter_mut().flatten() {
+            if *symbol == self.hardblank {
+                *symbol = ' ';
+            }
+        }
+        rows
+    }
+
+    /// The number of columns that a character overlaps the end of the rows by.
+    ///
+    /// This is the smallest number of blank columns between the end of the rows and the start of
+    /// the character, plus one for rows where the sub-characters that touch can be smushed.
+    fn overlap(
+        &self,
+        rows: &[Vec<(char, Style)>],
+        art: &[Vec<char>],
+        previous_width: usize,
+    ) -> usize {
+        if self.layout & (KERNING | SMUSHING) == 0 {
+            return 0;
+        }
+        let width = art.first().map_or(0, Vec::len);
+        let length = rows.first().map_or(0, Vec::len);
+        rows.iter()
+            .zip(art)
+            .map(|(row, art_row)| {
+                let trailing = row.iter().rev().take_while(|(c, _)| *c == ' ').count();
+                let leading = art_row.iter().take_while(|&&c| c == ' ').count();
+                let touching = row
+                    .len()
+                    .checked_sub(trailing + 1)
+                    .map(|index| row[index].0)
+                    .zip(art_row.get(leading));
+                let smushed = touching.is_some_and(|(left, &right)| {
+                    self.smush(left, right, previous_width, width).is_some()
+                });
+                trailing + leading + usize::from(smushed)
+            })
+            .min()
+            .unwrap_or(0)
+            .min(width)
+            .min(length)
+    }
+
+    /// The sub-character that two sub-characters are smushed into, if any.
+    ///
+    /// Blanks are always replaced by the other sub-character. Otherwise, characters narrower than
+    /// two columns are never smushed, and the smushing rules of the layout are tried in turn.
+    /// Without any rules, the right sub-character wins unless it is a hardblank.
+    fn smush(
+        &self,
+        left: char,
+        right: char,
+        left_width: usize,
+        right_width: usize,
+    ) -> Option<char> {
+        if left == ' ' {
+            return Some(right);
+        }
+        if right == ' ' {
+            return Some(left);
+        }
+        if left_width < 2 || right_width < 2 || self.layout & SMUSHING == 0 {
+            return None;
+        }
+        let hardblank = self.hardblank;
+        if self.layout & RULES == 0 {
+            return Some(if right == hardblank { left } else { right });
+        }
+        if left == hardblank || right == hardblank {
+            return (self.layout & HARDBLANK != 0 && left == right).then_some(left);
+        }
+        if self.layout & EQUAL != 0 && left == right {
+            return Some(left);
+        }
+        if self.layout & LOWLINE != 0 {
+            const REPLACES_LOWLINE: &str = "|/\\[]{}()<>";
+            if left == '_' && REPLACES_LOWLINE.contains(right) {
+                return Some(right);
+            }
+            if right == '_' && REPLACES_LOWLINE.contains(left) {
+                return Some(left);
+            }
+        }
+        if self.layout & HIERARCHY != 0 {
+            let class = |c| HIERARCHY_CLASSES.iter().position(|class| class.contains(c));
+            if let (Some(left_class), Some(right_class)) = (class(left), class(right)) {
+                if left_class != right_class {
+                    return Some(if left_class > right_class {
+                        left
+                    } else {
+                        right
+                    });
+                }
+            }
+        }
+        if self.layout & PAIR != 0
+            && matches!(
+                (left, right),
+                ('[', ']') | (']', '[') | ('{', '}') | ('}', '{') | ('(', ')') | (')', '(')
+            )
+        {
+            return Some('|');
+        }
+        if self.layout & BIG_X != 0 {
+            match (left, right) {
+                ('/', '\\') => return Some('|'),
+                ('\\', '/') => return Some('Y'),
+                ('>', '<') => return Some('X'),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Parses a FIGlet font.
+///
+/// See <http://www.jave.de/figlet/figfont.html> for the format. The header is followed by comment
+/// lines, the art of the printable ASCII characters and the [`DEUTSCH`] characters in order, and
+/// then by any number of characters that are tagged with their code. A file that ends before all
+/// of the required characters is accepted, as FIGlet does.
+fn parse(text: &str) -> Result<FigletFont, FontError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+    let header = lines
+        .next()
+        .and_then(|(_, line)| line.strip_prefix("flf2a"))
+        .ok_or(FontError::UnknownFormat)?;
+    let mut header = header.chars();
+    let hardblank = header
+        .next()
+        .filter(|c| !c.is_whitespace())
+        .ok_or_else(|| invalid(1, "missing hardblank"))?;
+    let args = header
+        .as_str()
+        .split_whitespace()
+        .take(7)
+        .map(str::parse::<i64>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| invalid(1, "invalid header"))?;
+    let [height, _baseline, _max_length, old_layout, comment_lines, ..] = args[..] else {
+        return Err(invalid(1, "missing header fields"));
+    };
+    let height = u16::try_from(height)
+        .ok()
+        .filter(|&height| height > 0)
+        .ok_or_else(|| invalid(1, "invalid height"))?;
+    let comment_lines = usize::try_from(comment_lines).map_err(|_| invalid(1, "invalid header"))?;
+    let layout = match args.get(6) {
+        Some(&full_layout) => {
+            u32::try_from(full_layout).map_err(|_| invalid(1, "invalid layout"))?
+        }
+        None => u32::try_from(old_layout).map_or(0, |old_layout| {
+            if old_layout == 0 {
+                KERNING
+            } else {
+                old_layout & RULES | SMUSHING
+            }
+        }),
+    };
+
+    let end = text.lines().count();
+    let mut lines = lines.skip(comment_lines).peekable();
+    let mut font = FigletFont {
+        height,
+        hardblank,
+        layout,
+        chars: BTreeMap::new(),
+    };
+    let required = (' '..='~').chain(DEUTSCH);
+    for c in required {
+        if lines.peek().is_none() {
+            return Ok(font);
+        }
+        let art = parse_char(&mut lines, height, end)?;
+        font.chars.entry(c).or_insert(art);
+    }
+    while let Some((number, line)) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let tag = line.split_whitespace().next().unwrap_or_default();
+        let code = parse_code(tag).ok_or_else(|| invalid(number, "invalid character code"))?;
+        let art = parse_char(&mut lines, height, end)?;
+        // negative codes are for characters that are only used by translation tables
+        if let Some(c) = u32::try_from(code).ok().and_then(char::from_u32) {
+            font.chars.entry(c).or_insert(art);
+        }
+    }
+    Ok(font)
+}
+
+/// Parses the rows of a character, removing the endmarks at the end of each row and padding the
+/// rows to the same width.
+fn parse_char<'t>(
+    lines: &mut impl Iterator<Item = (usize, &'t str)>,
+    height: u16,
+    end: usize,
+) -> Result<Vec<Vec<char>>, FontError> {
+    let mut art = (0..height)
+        .map(|_| {
+            let (_, line) = lines
+                .next()
+                .ok_or_else(|| invalid(end, "incomplete character"))?;
+            let line = line.trim_end();
+            let row = line
+                .chars()
+                .last()
+                .map_or(line, |endmark| line.trim_end_matches(endmark));
+            Ok(row.chars().collect::<Vec<_>>())
+        })
+        .collect::<Result<Vec<_>, FontError>>()?;
+    let width = art.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut art {
+        row.resize(width, ' ');
+    }
+    Ok(art)
+}
+
+/// Parses the code of a tagged character, which is decimal, hexadecimal with a `0x` prefix or
+/// octal with a `0` prefix, and may be negative.
+fn parse_code(tag: &str) -> Option<i64> {
+    let (sign, digits) = tag
+        .strip_prefix('-')
+        .map_or((1, tag), |digits| (-1, digits));
+    let value = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i64::from_str_radix(hex, 16)
+    } else if let Some(octal) = digits.strip_prefix('0').filter(|octal| !octal.is_empty()) {
+        i64::from_str_radix(octal, 8)
+    } else {
+        digits.parse()
+    };
+    value.ok().map(|value| sign * value)
+}
+
+const fn invalid(line: usize, reason: &'static str) -> FontError {
+    FontError::InvalidFiglet { line, reason }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use ratatui_core::style::{Color, Stylize};
+    use ratatui_core::text::Line;
+
+    use super::*;
+
+    /// Writes a FIGlet font with a height of 3 and the given header arguments after the height.
+    ///
+    /// Characters that are not given are empty, and characters outside of the required ones are
+    /// tagged with their code.
+    fn font_source(args: &str, chars: &[(char, [&str; 3])]) -> String {
+        let mut text = format!("flf2a$ 3 {args}\na test font\n");
+        let art = |c: char| {
+            chars
+                .iter()
+                .find(|(art_c, _)| *art_c == c)
+                .map_or(["", "", ""], |(_, art)| *art)
+        };
+        let required = (' '..='~').chain(DEUTSCH).collect::<Vec<_>>();
+        let tagged = chars
+            .iter()
+            .map(|(c, _)| *c)
+            .filter(|c| !required.contains(c));
+        for c in required.iter().copied() {
+            let [top, middle, bottom] = art(c);
+            text.push_str(&format!("{top}@\n{middle}@\n{bottom}@@\n"));
+        }
+        for c in tagged {
+            let [top, middle, bottom] = art(c);
+            let code = u32::from(c);
+            text.push_str(&format!("{code}  {c}\n{top}@\n{middle}@\n{bottom}@@\n"));
+        }
+        text
+    }
+
+    /// A font with smushing rules, where the space is a single hardblank.
+    pub(crate) fn test_font() -> FigletFont {
+        let source = font_source(
+            "2 6 31 1",
+            &[
+                (' ', ["$", "$", "$"]),
+                ('/', ["  /", " / ", "/  "]),
+                ('\\', ["\\  ", " \\ ", "  \\"]),
+                ('_', ["   ", "   ", "___"]),
+                ('|', [" | ", " | ", " | "]),
+                ('o', ["   ", " o ", "   "]),
+                ('€', ["===", "=C=", "==="]),
+            ],
+        );
+        FigletFont::from_bytes(source.as_bytes()).unwrap()
+    }
+
+    fn render(font: &FigletFont, text: &str) -> Vec<String> {
+        font.render_line(Line::from(text).styled_graphemes(Style::new()))
+            .into_iter()
+            .map(|row| row.into_iter().map(|(c, _)| c).collect())
+            .collect()
+    }
+
+    #[test]
+    fn parse_font() {
+        let font = test_font();
+        assert_eq!(font.height(), 3);
+        assert_eq!(font.hardblank, '$');
+        assert_eq!(font.layout, 31 | SMUSHING);
+        assert_eq!(font.len(), 103);
+        assert_eq!(font.chars[&'/'][1], vec![' ', '/', ' ']);
+        assert_eq!(font.chars[&'€'][1], vec!['=', 'C', '=']);
+        assert_eq!(font.chars[&'A'], vec![Vec::<char>::new(); 3]);
+    }
+
+    #[test]
+    fn full_layout() {
+        let source = font_source("2 6 -1 1 0 24463", &[]);
+        let font = FigletFont::from_bytes(source.as_bytes()).unwrap();
+        assert_eq!(font.layout, 24463);
+        let source = font_source("2 6 0 1", &[]);
+        let font = FigletFont::from_bytes(source.as_bytes()).unwrap();
+        assert_eq!(font.layout, KERNING);
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert!(matches!(
+            FigletFont::from_bytes(b"STARTFONT 2.1"),
+            Err(FontError::UnknownFormat)
+        ));
+    }
+
+    #[test]
+    fn invalid_header() {
+        assert!(matches!(
+            FigletFont::from_bytes(b"flf2a$ 3 2"),
+            Err(FontError::InvalidFiglet {
+                line: 1,
+                reason: "missing header fields"
+            })
+        ));
+        assert!(matches!(
+            FigletFont::from_bytes(b"flf2a$ 0 2 6 15 0"),
+            Err(FontError::InvalidFiglet {
+                line: 1,
+                reason: "invalid height"
+            })
+        ));
+    }
+
+    #[test]
+    fn truncated() {
+        // a file that ends between characters is accepted
+        let font = FigletFont::from_bytes(b"flf2a$ 1 1 2 0 0\n$@@\n!@@\n").unwrap();
+        assert_eq!(font.len(), 2);
+        assert!(matches!(
+            FigletFont::from_bytes(b"flf2a$ 2 1 2 0 0\n$@\n$@@\n!@\n"),
+            Err(FontError::InvalidFiglet {
+                line: 4,
+                reason: "incomplete character"
+            })
+        ));
+    }
+
+    #[test]
+    fn character_codes() {
+        assert_eq!(parse_code("196"), Some(196));
+        assert_eq!(parse_code("0x20AC"), Some(0x20ac));
+        assert_eq!(parse_code("0177"), Some(0o177));
+        assert_eq!(parse_code("0"), Some(0));
+        assert_eq!(parse_code("-0x2"), Some(-2));
+        assert_eq!(parse_code("x"), None);
+    }
+
+    #[test]
+    fn smushing_rules() {
+        let font = FigletFont {
+            hardblank: '$',
+            layout: RULES | SMUSHING,
+            ..FigletFont::default()
+        };
+        let smush = |left, right| font.smush(left, right, 2, 2);
+        assert_eq!(smush(' ', 'a'), Some('a'));
+        assert_eq!(smush('a', 'a'), Some('a'));
+        assert_eq!(smush('a', 'b'), None);
+        assert_eq!(smush('_', '/'), Some('/'));
+        assert_eq!(smush('|', '}'), Some('}'));
+        assert_eq!(smush('<', '['), Some('<'));
+        assert_eq!(smush('(', ')'), Some('|'));
+        assert_eq!(smush('/', '\\'), Some('|'));
+        assert_eq!(smush('\\', '/'), Some('Y'));
+        assert_eq!(smush('>', '<'), Some('X'));
+        assert_eq!(smush('$', '$'), Some('$'));
+        assert_eq!(smush('$', 'a'), None);
+        // narrow characters are never smushed
+        assert_eq!(font.smush('a', 'a', 1, 2), None);
+
+        let universal = FigletFont {
+            hardblank: '$',
+            layout: SMUSHING,
+            ..FigletFont::default()
+        };
+        assert_eq!(universal.smush('a', 'b', 2, 2), Some('b'));
+        assert_eq!(universal.smush('a', '$', 2, 2), Some('a'));
+    }
+
+    #[test]
+    fn render_smushed() {
+        let font = test_font();
+        assert_eq!(render(&font, "/\\"), vec!["  |  ", " / \\ ", "/   \\"]);
+        assert_eq!(render(&font, "\\/"), vec!["\\   /", " \\ / ", "  Y  "]);
+        assert_eq!(render(&font, "|_|"), vec![" | | ", " | | ", " |_| "]);
+        // the hardblank of the space is not smushed away, and is drawn as a space
+        assert_eq!(render(&font, "o o"), vec!["     ", " o o ", "     "]);
+        assert_eq!(render(&font, "?o"), vec!["   ", " o ", "   "]);
+    }
+
+    #[test]
+    fn render_kerned() {
+        let font = FigletFont {
+            layout: KERNING,
+            ..test_font()
+        };
+        assert_eq!(render(&font, "/\\"), vec!["  /\\  ", " /  \\ ", "/    \\"]);
+        assert_eq!(render(&font, "oo"), vec!["    ", " oo ", "    "]);
+        let full_width = FigletFont {
+            layout: 0,
+            ..test_font()
+        };
+        assert_eq!(
+            render(&full_width, "oo"),
+            vec!["      ", " o  o ", "      "]
+        );
+    }
+
+    #[test]
+    fn render_styles() {
+        let font = test_font();
+        let line = Line::from(vec!["|".red(), "_".blue()]);
+        let rows = font.render_line(line.styled_graphemes(Style::new()));
+        let styles = rows[2]
+            .iter()
+            .map(|(_, style)| style.fg)
+            .collect::<Vec<_>>();
+        let (red, blue) = (Some(Color::Red), Some(Color::Blue));
+        assert_eq!(styles, vec![red, red, blue, blue]);
+    }
+}
@@ -0,0 +1,367 @@
+use std::hash::{Hash, Hasher};
+use std::{fmt, ptr};
+
+use font8x8::UnicodeFonts;
+
+use crate::{Mirror, Rotation};
+
+/// The font used by [`BigText`](crate::BigText) when no other font is set.
+pub(crate) static DEFAULT_FONT: Font8x8 = Font8x8;
+
+/// A source of bitmap glyphs for [`BigText`](crate::BigText).
+///
+/// Implement this trait to render big text with a font other than the default [`Font8x8`], such
+/// as a condensed, wide or tall bitmap font. Glyphs can have any width and height up to 64
+/// pixels, and don't all need to be the same width.
+///
+/// # Example
+///
+/// ```rust
+/// use tui_big_text::{BigText, Font, Glyph};
+///
+/// /// A font where every glyph is a 3x5 block.
+/// #[derive(Debug)]
+/// struct BlockFont;
+///
+/// impl Font for BlockFont {
+///     fn width(&self) -> u16 {
+///         3
+///     }
+///
+///     fn height(&self) -> u16 {
+///         5
+///     }
+///
+///     fn glyph(&self, _grapheme: &str) -> Option<Glyph> {
+///         Some(Glyph::new(3, vec![0b111; 5]))
+///     }
+/// }
+///
+/// let big_text = BigText::builder()
+///     .font(&BlockFont)
+///     .lines(vec!["Hi".into()])
+///     .build();
+/// ```
+pub trait Font: fmt::Debug {
+    /// The width of a glyph in pixels.
+    ///
+    /// This is the width of the space left for graphemes that the font has no glyph for.
+    fn width(&self) -> u16;
+
+    /// The height of a line of text in pixels.
+    ///
+    /// Glyphs that are shorter than this are drawn at the top of the line.
+    fn height(&self) -> u16;
+
+    /// The glyph for a grapheme, or `None` if the font has no glyph for it.
+    ///
+    /// The grapheme is a whole grapheme cluster, which may be more than one character. When this
+    /// returns `None` for a cluster, [`BigText`](crate::BigText) tries the NFC normalized form of
+    /// the cluster, and then draws its combining diacritics over the glyph of the base character.
+    /// So most fonts only need to provide glyphs for single characters. Only the common accents
+    /// (grave, acute, circumflex, tilde, macron, diaeresis, ring and cedilla) can be drawn this
+    /// way, and other marks are left out.
+    fn glyph(&self, grapheme: &str) -> Option<Glyph>;
+}
+
+// Fonts are trait objects, so they are compared by identity rather than by value. This lets
+// `BigText` keep deriving `PartialEq`, `Eq` and `Hash`.
+impl PartialEq for dyn Font + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::addr_eq(self, other)
+    }
+}
+
+impl Eq for dyn Font + '_ {}
+
+impl Hash for dyn Font + '_ {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ptr::from_ref(self).cast::<()>().hash(state);
+    }
+}
+
+/// The bitmap of a single character of a [`Font`].
+///
+/// Each row of the bitmap is stored in a `u64`, where bit `x` is the pixel in column `x`, counting
+/// from the left. This matches the layout of the glyphs in the [font8x8] crate.
+///
+/// [font8x8]: https://crates.io/crates/font8x8
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Glyph {
+    width: u16,
+    rows: Vec<u64>,
+}
+
+impl Glyph {
+    /// Creates a glyph with the given width from rows of pixels, starting at the top.
+    ///
+    /// Widths greater than 64 are clamped to 64.
+    pub fn new(width: u16, rows: impl Into<Vec<u64>>) -> Self {
+        Self {
+            width: width.min(64),
+            rows: rows.into(),
+        }
+    }
+
+    /// Creates an 8x8 glyph from a bitmap in the format used by the [font8x8] crate.
+    ///
+    /// [font8x8]: https://crates.io/crates/font8x8
+    pub fn from_8x8(bitmap: [u8; 8]) -> Self {
+        Self::new(8, bitmap.map(u64::from))
+    }
+
+    /// The width of the glyph in pixels.
+    pub const fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// The height of the glyph in pixels.
+    pub fn height(&self) -> u16 {
+        self.rows.len() as u16
+    }
+
+    /// Draws another glyph over this one, so that a pixel is set if it is set in either glyph.
+    pub(crate) fn overlay(mut self, other: &Self) -> Self {
+        self.width = self.width.max(other.width);
+        if self.rows.len() < other.rows.len() {
+            self.rows.resize(other.rows.len(), 0);
+        }
+        for (row, other) in self.rows.iter_mut().zip(&other.rows) {
+            *row |= other;
+        }
+        self
+    }
+
+    /// Keeps only the pixels on the edge of the glyph, which are the set pixels with an unset pixel
+    /// above, below, left or right of them. Pixels outside the glyph count as unset.
+    pub(crate) fn outline(mut self) -> Self {
+        let mask = u64::MAX >> (64 - self.width.max(1));
+        let rows = self.rows.iter().map(|row| row & mask).collect::<Vec<_>>();
+        for (y, row) in self.rows.iter_mut().enumerate() {
+            let above = y.checked_sub(1).map_or(0, |y| rows[y]);
+            let below = rows.get(y + 1).copied().unwrap_or_default();
+            let pixels = rows[y];
+            let inside = pixels & above & below & (pixels << 1) & (pixels >> 1);
+            *row = pixels & !inside;
+        }
+        self
+    }
+
+    /// Moves the pixels of the glyph right by `x` and down by `y`, growing the glyph to fit them.
+    pub(crate) fn shifted(mut self, x: u16, y: u16) -> Self {
+        let x = x.min(64);
+        for row in &mut self.rows {
+            *row = row.checked_shl(x.into()).unwrap_or_default();
+        }
+        self.rows.splice(0..0, (0..y).map(|_| 0));
+        self.width = self.width.saturating_add(x).min(64);
+        self
+    }
+
+    /// Adds blank rows to the bottom of the glyph so that it is at least `height` pixels tall.
+    pub(crate) fn padded(mut self, height: u16) -> Self {
+        if self.rows.len() < usize::from(height) {
+            self.rows.resize(usize::from(height), 0);
+        }
+        self
+    }
+
+    /// Rotates the glyph clockwise, swapping its width and height for a quarter turn.
+    pub(crate) fn rotated(self, rotation: Rotation) -> Self {
+        let (width, height) = (self.width, self.height());
+        match rotation {
+            Rotation::None => self,
+            Rotation::Degrees90 => {
+                Self::from_fn(height, width, |x, y| self.pixel(y, height - 1 - x))
+            }
+            Rotation::Degrees180 => Self::from_fn(width, height, |x, y| {
+                self.pixel(width - 1 - x, height - 1 - y)
+            }),
+            Rotation::Degrees270 => {
+                Self::from_fn(height, width, |x, y| self.pixel(width - 1 - y, x))
+            }
+        }
+    }
+
+    /// Mirrors the glyph horizontally or vertically.
+    pub(crate) fn mirrored(mut self, mirror: Mirror) -> Self {
+        match mirror {
+            Mirror::None => self,
+            Mirror::Horizontal => {
+                let width = self.width;
+                Self::from_fn(width, self.height(), |x, y| self.pixel(width - 1 - x, y))
+            }
+            Mirror::Vertical => {
+                self.rows.reverse();
+                self
+            }
+        }
+    }
+
+    /// Creates a glyph of the given size, where `pixel` returns whether each pixel is set.
+    fn from_fn(width: u16, height: u16, pixel: impl Fn(u16, u16) -> bool) -> Self {
+        let width = width.min(64);
+        let rows = (0..height)
+            .map(|y| {
+                (0..width)
+                    .filter(|&x| pixel(x, y))
+                    .fold(0, |row, x| row | 1 << x)
+            })
+            .collect::<Vec<_>>();
+        Self::new(width, rows)
+    }
+
+    /// Returns `true` if the pixel at the given column and row is set.
+    ///
+    /// Pixels outside the glyph are never set.
+    pub fn pixel(&self, x: u16, y: u16) -> bool {
+        x < self.width
+            && self
+                .rows
+                .get(usize::from(y))
+                .is_some_and(|row| row & (1 << x) != 0)
+    }
+}
+
+/// The 8x8 font from the [font8x8] crate.
+///
+/// This is the default font of [`BigText`](crate::BigText). Characters are looked up in each of the
+/// font8x8 tables in turn, which cover basic and accented latin, greek, box drawing, block
+/// elements, hiragana and a few miscellaneous symbols. Other scripts, such as cyrillic, are not
+/// covered by font8x8; use a [`BitmapFont`](crate::BitmapFont) loaded from a font file for those.
+///
+/// [font8x8]: https://crates.io/crates/font8x8
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Font8x8;
+
+impl Font for Font8x8 {
+    fn width(&self) -> u16 {
+        8
+    }
+
+    fn height(&self) -> u16 {
+        8
+    }
+
+    fn glyph(&self, grapheme: &str) -> Option<Glyph> {
+        let c = single_char(grapheme)?;
+        FONT8X8_TABLES
+            .iter()
+            .find_map(|table| table.get(c))
+            .map(Glyph::from_8x8)
+    }
+}
+
+/// The character of a grapheme that is a single character.
+pub(crate) fn single_char(grapheme: &str) -> Option<char> {
+    let mut chars = grapheme.chars();
+    chars.next().filter(|_| chars.next().is_none())
+}
+
+/// The tables of the font8x8 crate, in the order that characters are looked up in.
+static FONT8X8_TABLES: [&(dyn UnicodeFonts + Sync); 8] = [
+    &font8x8::BASIC_FONTS,
+    &font8x8::LATIN_FONTS,
+    &font8x8::GREEK_FONTS,
+    &font8x8::BOX_FONTS,
+    &font8x8::BLOCK_FONTS,
+    &font8x8::HIRAGANA_FONTS,
+    &font8x8::MISC_FONTS,
+    &font8x8::SGA_FONTS,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyph_pixel() {
+        let glyph = Glyph::new(3, vec![0b001, 0b110]);
+        assert_eq!(glyph.width(), 3);
+        assert_eq!(glyph.height(), 2);
+        assert!(glyph.pixel(0, 0));
+        assert!(!glyph.pixel(1, 0));
+        assert!(glyph.pixel(2, 1));
+        assert!(!glyph.pixel(0, 2));
+        assert!(!glyph.pixel(3, 0));
+    }
+
+    #[test]
+    fn glyph_overlay() {
+        let glyph = Glyph::new(2, vec![0b01]).overlay(&Glyph::new(3, vec![0b10, 0b100]));
+        assert_eq!(glyph, Glyph::new(3, vec![0b11, 0b100]));
+    }
+
+    #[test]
+    fn glyph_outline() {
+        let glyph = Glyph::new(4, vec![0b0111, 0b0111, 0b0111, 0b1111]).outline();
+        assert_eq!(glyph, Glyph::new(4, vec![0b0111, 0b0101, 0b0101, 0b1111]));
+    }
+
+    #[test]
+    fn glyph_shifted() {
+        let glyph = Glyph::new(2, vec![0b01, 0b11]).shifted(1, 2);
+        assert_eq!(glyph, Glyph::new(3, vec![0, 0, 0b010, 0b110]));
+    }
+
+    #[test]
+    fn glyph_rotated() {
+        // an L shape
+        let glyph = Glyph::new(3, vec![0b001, 0b111]);
+        assert_eq!(glyph.clone().rotated(Rotation::None), glyph);
+        assert_eq!(
+            glyph.clone().rotated(Rotation::Degrees90),
+            Glyph::new(2, vec![0b11, 0b01, 0b01])
+        );
+        assert_eq!(
+            glyph.clone().rotated(Rotation::Degrees180),
+            Glyph::new(3, vec![0b111, 0b100])
+        );
+        assert_eq!(
+            glyph.rotated(Rotation::Degrees270),
+            Glyph::new(2, vec![0b10, 0b10, 0b11])
+        );
+    }
+
+    #[test]
+    fn glyph_mirrored() {
+        let glyph = Glyph::new(3, vec![0b001, 0b111]);
+        assert_eq!(
+            glyph.clone().mirrored(Mirror::Horizontal),
+            Glyph::new(3, vec![0b100, 0b111])
+        );
+        assert_eq!(
+            glyph.mirrored(Mirror::Vertical),
+            Glyph::new(3, vec![0b111, 0b001])
+        );
+    }
+
+    #[test]
+    fn font8x8_glyph() {
+        let glyph = Font8x8.glyph("!").unwrap();
+        assert_eq!(
+            glyph,
+            Glyph::from_8x8(font8x8::BASIC_FONTS.get('!').unwrap())
+        );
+        assert_eq!(Font8x8.glyph("Ж"), None);
+        assert_eq!(Font8x8.glyph("!!"), None);
+    }
+
+    #[test]
+    fn font8x8_extended_glyphs() {
+        for grapheme in ["é", "λ", "┼", "▒", "あ"] {
+            let glyph = Font8x8.glyph(grapheme).unwrap();
+            let pixels = (0..8).flat_map(|y| (0..8).map(move |x| (x, y)));
+            assert!(
+                pixels.into_iter().any(|(x, y)| glyph.pixel(x, y)),
+                "{grapheme}"
+            );
+        }
+    }
+
+    #[test]
+    fn fonts_are_compared_by_identity() {
+        let font: &dyn Font = &DEFAULT_FONT;
+        assert_eq!(font, &DEFAULT_FONT as &dyn Font);
+    }
+}
@@ -0,0 +1,215 @@
+//! Looks up the glyphs of grapheme clusters that fonts have no glyph for as a whole.
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{Font, Glyph};
+
+/// Combining diacritics and the spacing characters that are drawn over a base glyph for them.
+const COMBINING_MARKS: [(char, char); 8] = [
+    ('\u{300}', '`'),
+    ('\u{301}', '´'),
+    ('\u{302}', '^'),
+    ('\u{303}', '~'),
+    ('\u{304}', '¯'),
+    ('\u{308}', '¨'),
+    ('\u{30A}', '°'),
+    ('\u{327}', '¸'),
+];
+
+/// The ranges of pictographic characters that are drawn as emoji, from the `Extended_Pictographic`
+/// property of Unicode 15.1, along with the rest of the emoji planes (which include the regional
+/// indicators of flags and the skin tone modifiers).
+const EMOJI: [(char, char); 52] = [
+    ('\u{A9}', '\u{A9}'),
+    ('\u{AE}', '\u{AE}'),
+    ('\u{203C}', '\u{203C}'),
+    ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{2194}', '\u{2199}'),
+    ('\u{21A9}', '\u{21AA}'),
+    ('\u{231A}', '\u{231B}'),
+    ('\u{2328}', '\u{2328}'),
+    ('\u{2388}', '\u{2388}'),
+    ('\u{23CF}', '\u{23CF}'),
+    ('\u{23E9}', '\u{23F3}'),
+    ('\u{23F8}', '\u{23FA}'),
+    ('\u{24C2}', '\u{24C2}'),
+    ('\u{25AA}', '\u{25AB}'),
+    ('\u{25B6}', '\u{25B6}'),
+    ('\u{25C0}', '\u{25C0}'),
+    ('\u{25FB}', '\u{25FE}'),
+    ('\u{2600}', '\u{2605}'),
+    ('\u{2607}', '\u{2612}'),
+    ('\u{2614}', '\u{2685}'),
+    ('\u{2690}', '\u{2705}'),
+    ('\u{2708}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271D}', '\u{271D}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2767}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27A1}', '\u{27A1}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2934}', '\u{2935}'),
+    ('\u{2B05}', '\u{2B07}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303D}', '\u{303D}'),
+    ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'),
+    ('\u{1F000}', '\u{1FAFF}'),
+    ('\u{1FC00}', '\u{1FFFD}'),
+];
+
+/// The glyph for a grapheme cluster.
+///
+/// The cluster is looked up as a whole first, and then in its NFC normalized form. Emoji are drawn
+/// as a placeholder box, and other clusters as the glyph of their base character with any known
+/// combining diacritics drawn over it. Returns `None` if the font has no glyph for the base
+/// character.
+///
+/// Only the marks in [`COMBINING_MARKS`] can be drawn over a base glyph. Any other mark that is
+/// left after normalization, such as a mark on a letter that has no precomposed form, is not
+/// drawn, so the base glyph is drawn without it.
+pub(crate) fn glyph(font: &dyn Font, grapheme: &str) -> Option<Glyph> {
+    if let Some(glyph) = font.glyph(grapheme) {
+        return Some(glyph);
+    }
+    if is_emoji(grapheme) {
+        return Some(placeholder(font.width(), font.height()));
+    }
+    let composed = grapheme.nfc().collect::<String>();
+    if composed != grapheme {
+        if let Some(glyph) = font.glyph(&composed) {
+            return Some(glyph);
+        }
+    }
+    let mut chars = composed.chars();
+    let base = chars.next()?;
+    if chars.as_str().is_empty() {
+        return None;
+    }
+    let base = font.glyph(base.encode_utf8(&mut [0; 4]))?;
+    let glyph = chars
+        .filter_map(|mark| {
+            let (_, spacing) = COMBINING_MARKS.iter().find(|(c, _)| *c == mark)?;
+            font.glyph(spacing.encode_utf8(&mut [0; 4]))
+        })
+        .fold(base, |glyph, mark| glyph.overlay(&mark));
+    Some(glyph)
+}
+
+/// Returns `true` if the grapheme is an emoji, including flags, keycaps and ZWJ sequences.
+///
+/// Pictographic characters are emoji whether or not they are followed by the emoji presentation
+/// selector, but fonts that have a glyph for one are asked for it first.
+fn is_emoji(grapheme: &str) -> bool {
+    grapheme.chars().any(|c| {
+        matches!(
+            c,
+            '\u{200D}' // zero width joiner
+            | '\u{20E3}' // combining enclosing keycap
+            | '\u{FE0F}' // emoji presentation selector
+        ) || EMOJI.iter().any(|&(start, end)| (start..=end).contains(&c))
+    })
+}
+
+/// A box outline that fills a glyph of the given size, leaving the last column and row blank as
+/// space between glyphs.
+fn placeholder(width: u16, height: u16) -> Glyph {
+    let right = width.min(64).saturating_sub(2);
+    let bottom = height.saturating_sub(2);
+    let full = (0..=right).fold(0, |row, x| row | 1 << x);
+    let sides = 1 | 1 << right;
+    let rows = (0..height).map(|y| match y {
+        0 => full,
+        y if y == bottom => full,
+        y if y < bottom => sides,
+        _ => 0,
+    });
+    Glyph::new(width, rows.collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Font8x8;
+
+    #[test]
+    fn single_character() {
+        assert_eq!(glyph(&Font8x8, "a"), Font8x8.glyph("a"));
+        assert_eq!(glyph(&Font8x8, "Ж"), None);
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(glyph(&Font8x8, "e\u{301}"), Font8x8.glyph("é"));
+        assert_eq!(glyph(&Font8x8, "A\u{30A}"), Font8x8.glyph("Å"));
+    }
+
+    #[test]
+    fn combining_marks() {
+        // there is no precomposed "x̃", so the tilde is drawn over the "x"
+        let x = Font8x8.glyph("x").unwrap();
+        let tilde = Font8x8.glyph("~").unwrap();
+        assert_eq!(glyph(&Font8x8, "x\u{303}"), Some(x.clone().overlay(&tilde)));
+        // unknown marks are left out
+        assert_eq!(glyph(&Font8x8, "x\u{33D}"), Some(x));
+        assert_eq!(glyph(&Font8x8, "Ж\u{301}"), None);
+    }
+
+    #[test]
+    fn marks_that_do_not_compose() {
+        // there is no precomposed "q̌", and the caron has no spacing character to draw, so only
+        // the "q" is drawn
+        let q = Font8x8.glyph("q").unwrap();
+        assert_eq!(glyph(&Font8x8, "q\u{30C}"), Some(q));
+        // stacked marks are drawn if they are known and left out otherwise
+        let x = Font8x8.glyph("x").unwrap();
+        let tilde = Font8x8.glyph("~").unwrap();
+        assert_eq!(glyph(&Font8x8, "x\u{30C}\u{303}"), Some(x.overlay(&tilde)));
+    }
+
+    #[test]
+    fn emoji() {
+        let expected = Some(placeholder(8, 8));
+        assert_eq!(glyph(&Font8x8, "😀"), expected);
+        assert_eq!(glyph(&Font8x8, "👩\u{200D}💻"), expected);
+        assert_eq!(glyph(&Font8x8, "🇳🇿"), expected);
+        assert_eq!(glyph(&Font8x8, "1\u{FE0F}\u{20E3}"), expected);
+    }
+
+    #[test]
+    fn emoji_without_presentation_selector() {
+        let expected = Some(placeholder(8, 8));
+        assert_eq!(glyph(&Font8x8, "\u{2600}"), expected); // ☀
+        assert_eq!(glyph(&Font8x8, "\u{2764}"), expected); // ❤
+        assert_eq!(glyph(&Font8x8, "\u{231A}"), expected); // ⌚
+        assert_eq!(glyph(&Font8x8, "\u{2705}"), expected); // ✅
+                                                           // characters that the font has a glyph for are drawn with it
+        assert_eq!(glyph(&Font8x8, "©"), Font8x8.glyph("©"));
+    }
+
+    #[test]
+    fn placeholder_box() {
+        let glyph = &placeholder(4, 5);
+        let rows = (0..5)
+            .map(|y| (0..4).map(move |x| if glyph.pixel(x, y) { '#' } else { '.' }))
+            .map(String::from_iter)
+            .collect::<Vec<_>>();
+        assert_eq!(rows, ["###.", "#.#.", "#.#.", "###.", "...."]);
+    }
+}
@@ -76,6 +76,75 @@
 //!
 //! ![Alignment Example](https://vhs.charm.sh/vhs-2GdJCPpXfnOCTsykSPr7AW.gif)
 //!
+//! Text can also be aligned to the Top / Middle / Bottom of the area, and the spacing between
+//! glyphs and between lines can be set in pixels.
+//!
+//! ```rust
+//! # use tui_big_text::*;
+//! BigText::builder().top_aligned();
+//! BigText::builder().vertically_centered();
+//! BigText::builder().bottom_aligned();
+//! BigText::builder().letter_spacing(1).line_spacing(2);
+//! ```
+//!
+//! Lines that are too wide for the area are truncated, unless the `wrap` method is used to wrap
+//! them at word boundaries. The `fit` method picks the largest pixel size whose output fits the
+//! area, which is useful when the size of the terminal varies.
+//!
+//! The `size`, `width` and `height` methods return the number of cells that the text takes up, for
+//! example to center it using a layout or to show it in a popup.
+//!
+//! Glyphs come from the [`Font8x8`] font by default. Use the `font` method to render text with any
+//! other type that implements the [`Font`] trait, such as a condensed, wide or tall bitmap font.
+//! Glyphs in a font don't all need to be the same width. Fonts in the BDF and PSF formats, such as
+//! Terminus and Spleen, can be loaded at runtime with [`BitmapFont`].
+//!
+//! The default font covers latin, greek, box drawing, block elements and hiragana characters.
+//! Characters that the font has no glyph for are left blank, unless a replacement is set using the
+//! `fallback` method.
+//!
+//! For numbers, the [`SevenSegment`], [`SegmentedLcd`] and [`RoundedDigits`] fonts draw digits in
+//! the style of a digital clock, a calculator display, or smooth hand-drawn strokes. They cover the
+//! digits, `:`, `.`, `-` and `%`, and the units `°`, `C`, `F`, `h`, `s` and `A`.
+//!
+//! [FIGlet] fonts, which draw each character with other characters instead of pixels, can be loaded
+//! from `.flf` files with [`FigletFont`] and set with the `figlet_font` method. Characters are
+//! moved together (kerned) or overlapped (smushed) following the layout rules of the font, and the
+//! lines are styled and aligned in the same way as with a bitmap font.
+//!
+//! The `gradient` method colors the text with a [`TextGradient`] that runs across or down each
+//! line, the `shadow` method draws a drop shadow offset by a number of pixels, and the `outline`
+//! method draws only the edges of each glyph.
+//!
+//! ```rust
+//! use ratatui::style::Color;
+//! use tui_big_text::{BigText, Shadow};
+//!
+//! BigText::builder()
+//!     .shadow(Shadow::new(1, 1, Color::DarkGray))
+//!     .outline(true);
+//! ```
+//!
+//! The `rotation` and `mirror` methods turn each glyph by a [`Rotation`] of 90, 180 or 270 degrees,
+//! or flip it with a [`Mirror`], before it is mapped to cells with the pixel size. A quarter turn
+//! rotates the lines with their glyphs, so that they run down or up the area, which draws text that
+//! runs down the side of the screen such as a sidebar banner.
+//!
+//! The [`Marquee`], [`Typewriter`] and [`Transition`] widgets animate a `BigText`. A marquee
+//! scrolls the text sideways, a typewriter reveals it one grapheme at a time, and a transition
+//! slides or flips from one text to another. Each is a stateful widget whose state is advanced on
+//! each tick of a timer or by the time elapsed since the last frame. Text moves a pixel at a time,
+//! so it moves in steps smaller than a cell when a cell holds more than one pixel.
+//!
+//! `BigText` is also a stateful widget, which turns it into a large-print input field. A
+//! [`BigTextState`] holds a cursor and a selection, which are drawn as inverted cells, and maps a
+//! mouse click back to the line and grapheme that it landed on.
+//!
+//! Outside of a terminal UI, such as for a banner in `--help` output or a log,
+//! [`BigText::to_lines`] renders the text into styled lines without a `Buffer`, and
+//! [`BigText::to_ansi_string`] renders it into a string with ANSI escape codes for its colors.
+//! `BigText` also implements `Display`, which renders it as plain text.
+//!
 //! # More widgets
 //!
 //! For the full suite of widgets, see [tui-widgets].
@@ -83,12 +152,31 @@
 //! [tui-big-text]: https://crates.io/crates/tui-big-text
 //! [Ratatui]: https://crates.io/crates/ratatui
 //! [font8x8]: https://crates.io/crates/font8x8
+//! [FIGlet]: http://www.figlet.org/
 //!
 //! <!-- Note that these links are sensitive to breaking with cargo-rdme -->
 //! [`BigText`]: https://docs.rs/tui-big-text/tui_big_text/big_text/struct.BigText.html
 //! [`BigText::builder`]:
 //!     https://docs.rs/tui-big-text/tui_big_text/big_text/struct.BigText.html#method.builder
 //! [`PixelSize`]: https://docs.rs/tui-big-text/tui_big_text/pixel_size/enum.PixelSize.html
+//! [`Font`]: https://docs.rs/tui-big-text/tui_big_text/trait.Font.html
+//! [`Font8x8`]: https://docs.rs/tui-big-text/tui_big_text/struct.Font8x8.html
+//! [`BitmapFont`]: https://docs.rs/tui-big-text/tui_big_text/struct.BitmapFont.html
+//! [`SevenSegment`]: https://docs.rs/tui-big-text/tui_big_text/struct.SevenSegment.html
+//! [`SegmentedLcd`]: https://docs.rs/tui-big-text/tui_big_text/struct.SegmentedLcd.html
+//! [`RoundedDigits`]: https://docs.rs/tui-big-text/tui_big_text/struct.RoundedDigits.html
+//! [`FigletFont`]: https://docs.rs/tui-big-text/tui_big_text/struct.FigletFont.html
+//! [`TextGradient`]: https://docs.rs/tui-big-text/tui_big_text/struct.TextGradient.html
+//! [`Rotation`]: https://docs.rs/tui-big-text/tui_big_text/enum.Rotation.html
+//! [`Mirror`]: https://docs.rs/tui-big-text/tui_big_text/enum.Mirror.html
+//! [`Marquee`]: https://docs.rs/tui-big-text/tui_big_text/struct.Marquee.html
+//! [`Typewriter`]: https://docs.rs/tui-big-text/tui_big_text/struct.Typewriter.html
+//! [`Transition`]: https://docs.rs/tui-big-text/tui_big_text/struct.Transition.html
+//! [`BigTextState`]: https://docs.rs/tui-big-text/tui_big_text/struct.BigTextState.html
+//! [`BigText::to_lines`]:
+//!     https://docs.rs/tui-big-text/tui_big_text/big_text/struct.BigText.html#method.to_lines
+//! [`BigText::to_ansi_string`]:
+//!     https://docs.rs/tui-big-text/tui_big_text/big_text/struct.BigText.html#method.to_ansi_string
 //! [`render_widget`]: https://docs.rs/ratatui/ratatui/struct.Frame.html#method.render_widget
 //! [`Style`]: https://docs.rs/ratatui/ratatui/style/struct.Style.html
 //!
@@ -115,8 +203,31 @@
 //! [Joshka]: https://github.com/joshka
 //! [tui-widgets]: https://crates.io/crates/tui-widgets
 
+mod animation;
+mod ansi;
+mod bdf;
 mod big_text;
+mod bitmap_font;
+mod digits;
+mod effect;
+mod figlet;
+mod font;
+mod grapheme;
 mod pixel_size;
+mod psf;
+mod state;
+mod transform;
 
+pub use animation::{
+    Marquee, MarqueeState, Transition, TransitionEffect, TransitionState, Typewriter,
+    TypewriterState,
+};
 pub use big_text::{BigText, BigTextBuilder};
+pub use bitmap_font::{BitmapFont, FontError};
+pub use digits::{RoundedDigits, SegmentedLcd, SevenSegment};
+pub use effect::{Shadow, TextGradient};
+pub use figlet::FigletFont;
+pub use font::{Font, Font8x8, Glyph};
 pub use pixel_size::PixelSize;
+pub use state::{BigTextState, TextPosition};
+pub use transform::{Mirror, Rotation};
@@ -1,3 +1,5 @@
+use crate::Glyph;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
 pub enum PixelSize {
     #[default]
@@ -35,12 +37,27 @@ pub enum PixelSize {
 }
 
 impl PixelSize {
+    /// The pixel sizes ordered from the largest pixels to the smallest.
+    ///
+    /// Where two pixel sizes have pixels of the same area, the one whose pixels are closer to
+    /// square, or whose symbols are more widely supported by terminals, comes first.
+    pub(crate) const LARGEST_FIRST: [Self; 8] = [
+        Self::Full,
+        Self::HalfHeight,
+        Self::HalfWidth,
+        Self::ThirdHeight,
+        Self::Quadrant,
+        Self::QuarterHeight,
+        Self::Sextant,
+        Self::Octant,
+    ];
+
     /// The number of pixels that can be displayed in a single character cell for the given
     /// pixel size.
     ///
     /// The first value is the number of pixels in the horizontal direction, the second value is
     /// the number of pixels in the vertical direction.
-    pub(crate) const fn pixels_per_cell(self) -> (u16, u16) {
+    pub const fn pixels_per_cell(self) -> (u16, u16) {
         match self {
             Self::Full => (1, 1),
             Self::HalfHeight => (1, 2),
@@ -54,138 +71,43 @@ impl PixelSize {
     }
 
     /// Get a symbol/char that represents the pixels at the given position with the given pixel size
-    pub(crate) const fn symbol_for_position(self, glyph: &[u8; 8], row: usize, col: i32) -> char {
+    ///
+    /// The position is the column and row of the top left pixel of the cell in the glyph. Pixels
+    /// outside the glyph are treated as unset.
+    pub(crate) fn symbol_for_position(self, glyph: &Glyph, row: u16, col: u16) -> char {
+        let pixel = |x: u16, y: u16| u8::from(glyph.pixel(col + x, row + y));
         match self {
-            Self::Full => match glyph[row] & (1 << col) {
+            Self::Full => match pixel(0, 0) {
                 0 => ' ',
                 _ => '█',
             },
-            Self::HalfHeight => {
-                let top = glyph[row] & (1 << col);
-                let bottom = glyph[row + 1] & (1 << col);
-                get_symbol_half_height(top, bottom)
-            }
-            Self::HalfWidth => {
-                let left = glyph[row] & (1 << col);
-                let right = glyph[row] & (1 << (col + 1));
-                get_symbol_half_width(left, right)
-            }
+            Self::HalfHeight => get_symbol_half_height(pixel(0, 0), pixel(0, 1)),
+            Self::HalfWidth => get_symbol_half_width(pixel(0, 0), pixel(1, 0)),
             Self::Quadrant => {
-                let top_left = glyph[row] & (1 << col);
-                let top_right = glyph[row] & (1 << (col + 1));
-                let bottom_left = glyph[row + 1] & (1 << col);
-                let bottom_right = glyph[row + 1] & (1 << (col + 1));
-                get_symbol_quadrant_size(top_left, top_right, bottom_left, bottom_right)
-            }
-            Self::ThirdHeight => {
-                let top = glyph[row] & (1 << col);
-                let is_middle_available = (row + 1) < glyph.len();
-                let middle = if is_middle_available {
-                    glyph[row + 1] & (1 << col)
-                } else {
-                    0
-                };
-                let is_bottom_available = (row + 2) < glyph.len();
-                let bottom = if is_bottom_available {
-                    glyph[row + 2] & (1 << col)
-                } else {
-                    0
-                };
-                get_symbol_third_height(top, middle, bottom)
-            }
-            Self::Sextant => {
-                let top_left = glyph[row] & (1 << col);
-                let top_right = glyph[row] & (1 << (col + 1));
-                let is_middle_available = (row + 1) < glyph.len();
-                let (middle_left, middle_right) = if is_middle_available {
-                    (
-                        glyph[row + 1] & (1 << col),
-                        glyph[row + 1] & (1 << (col + 1)),
-                    )
-                } else {
-                    (0, 0)
-                };
-                let is_bottom_available = (row + 2) < glyph.len();
-                let (bottom_left, bottom_right) = if is_bottom_available {
-                    (
-                        glyph[row + 2] & (1 << col),
-                        glyph[row + 2] & (1 << (col + 1)),
-                    )
-                } else {
-                    (0, 0)
-                };
-                get_symbol_sextant_size(
-                    top_left,
-                    top_right,
-                    middle_left,
-                    middle_right,
-                    bottom_left,
-                    bottom_right,
-                )
+                get_symbol_quadrant_size(pixel(0, 0), pixel(1, 0), pixel(0, 1), pixel(1, 1))
             }
+            Self::ThirdHeight => get_symbol_third_height(pixel(0, 0), pixel(0, 1), pixel(0, 2)),
+            Self::Sextant => get_symbol_sextant_size(
+                pixel(0, 0),
+                pixel(1, 0),
+                pixel(0, 1),
+                pixel(1, 1),
+                pixel(0, 2),
+                pixel(1, 2),
+            ),
             Self::QuarterHeight => {
-                let top = glyph[row] & (1 << col);
-                let is_upper_middle_available = (row + 1) < glyph.len();
-                let upper_middle = if is_upper_middle_available {
-                    glyph[row + 1] & (1 << col)
-                } else {
-                    0
-                };
-                let is_lower_middle_available = (row + 2) < glyph.len();
-                let lower_middle = if is_lower_middle_available {
-                    glyph[row + 2] & (1 << col)
-                } else {
-                    0
-                };
-                let is_bottom_available = (row + 3) < glyph.len();
-                let bottom = if is_bottom_available {
-                    glyph[row + 3] & (1 << col)
-                } else {
-                    0
-                };
-                get_symbol_quarter_height(top, upper_middle, lower_middle, bottom)
-            }
-            Self::Octant => {
-                let top_left = glyph[row] & (1 << col);
-                let top_right = glyph[row] & (1 << (col + 1));
-                let is_upper_middle_available = (row + 1) < glyph.len();
-                let (upper_middle_left, upper_middle_right) = if is_upper_middle_available {
-                    (
-                        glyph[row + 1] & (1 << col),
-                        glyph[row + 1] & (1 << (col + 1)),
-                    )
-                } else {
-                    (0, 0)
-                };
-                let is_lower_middle_available = (row + 2) < glyph.len();
-                let (lower_middle_left, lower_middle_right) = if is_lower_middle_available {
-                    (
-                        glyph[row + 2] & (1 << col),
-                        glyph[row + 2] & (1 << (col + 1)),
-                    )
-                } else {
-                    (0, 0)
-                };
-                let is_bottom_available = (row + 3) < glyph.len();
-                let (bottom_left, bottom_right) = if is_bottom_available {
-                    (
-                        glyph[row + 3] & (1 << col),
-                        glyph[row + 3] & (1 << (col + 1)),
-                    )
-                } else {
-                    (0, 0)
-                };
-                get_symbol_octant_size(
-                    top_left,
-                    top_right,
-                    upper_middle_left,
-                    upper_middle_right,
-                    lower_middle_left,
-                    lower_middle_right,
-                    bottom_left,
-                    bottom_right,
-                )
+                get_symbol_quarter_height(pixel(0, 0), pixel(0, 1), pixel(0, 2), pixel(0, 3))
             }
+            Self::Octant => get_symbol_octant_size(
+                pixel(0, 0),
+                pixel(1, 0),
+                pixel(0, 1),
+                pixel(1, 1),
+                pixel(0, 2),
+                pixel(1, 2),
+                pixel(0, 3),
+                pixel(1, 3),
+            ),
         }
     }
 }
@@ -770,7 +692,7 @@ mod tests {
         // We expect that pixels out of the glyph-bounds are not set
         // Returned character is upper third filled only
 
-        let glyph = [0xFFu8; 8];
+        let glyph = Glyph::from_8x8([0xFFu8; 8]);
         assert_eq!(
             PixelSize::ThirdHeight.symbol_for_position(&glyph, 7, 0),
             '🬂'
@@ -784,7 +706,7 @@ mod tests {
         // We expect that pixels out of the glyph-bounds are not set
         // Returned character is upper third filled only
 
-        let glyph = [0xFFu8; 8];
+        let glyph = Glyph::from_8x8([0xFFu8; 8]);
         assert_eq!(PixelSize::Sextant.symbol_for_position(&glyph, 7, 0), '🬂');
         Ok(())
     }
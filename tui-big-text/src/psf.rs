@@ -0,0 +1,325 @@
+//! A parser for PC Screen Fonts (PSF), the format of Linux console fonts.
+//!
+//! See <https://www.win.tue.nl/~aeb/linux/kbd/font-formats-1.html> for a description of versions
+//! 1 and 2 of the format. When a font has no unicode table, glyph `n` is used for the character
+//! with code point `n`.
+
+use crate::bitmap_font::glyph_row;
+use crate::{BitmapFont, FontError, Glyph};
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF1_MODE_512: u8 = 0x01;
+const PSF1_MODE_HAS_TABLE: u8 = 0x06;
+const PSF1_SEPARATOR: u16 = 0xFFFF;
+const PSF1_START_SEQUENCE: u16 = 0xFFFE;
+
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+const PSF2_HAS_TABLE: u32 = 0x01;
+const PSF2_SEPARATOR: u8 = 0xFF;
+const PSF2_START_SEQUENCE: u8 = 0xFE;
+
+/// Returns `true` if the data looks like a PSF font.
+pub(crate) fn is_psf(data: &[u8]) -> bool {
+    data.starts_with(&PSF1_MAGIC) || data.starts_with(&PSF2_MAGIC)
+}
+
+/// Parses a PSF font, version 1 or 2.
+pub(crate) fn parse(data: &[u8]) -> Result<BitmapFont, FontError> {
+    if data.starts_with(&PSF2_MAGIC) {
+        parse_psf2(data)
+    } else if data.starts_with(&PSF1_MAGIC) {
+        parse_psf1(data)
+    } else {
+        Err(FontError::InvalidPsf("missing magic number"))
+    }
+}
+
+fn parse_psf1(data: &[u8]) -> Result<BitmapFont, FontError> {
+    let [_, _, mode, height, ..] = *data else {
+        return Err(FontError::InvalidPsf("truncated header"));
+    };
+    if height == 0 {
+        return Err(FontError::InvalidPsf("invalid height"));
+    }
+    let count = if mode & PSF1_MODE_512 == 0 { 256 } else { 512 };
+    let glyphs = Glyphs::new(&data[4..], count, 8, height.into(), height.into())?;
+    let mut font = BitmapFont::new(8, height.into());
+    if mode & PSF1_MODE_HAS_TABLE == 0 {
+        glyphs.insert_by_index(&mut font);
+        return Ok(font);
+    }
+    let mut table = glyphs
+        .rest
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]));
+    for index in 0..count {
+        let glyph = glyphs.get(index);
+        let mut in_sequence = false;
+        loop {
+            match table.next() {
+                Some(PSF1_SEPARATOR) => break,
+                Some(PSF1_START_SEQUENCE) => in_sequence = true,
+                Some(code) if !in_sequence => {
+                    if let Some(c) = char::from_u32(code.into()) {
+                        font.insert(c, glyph.clone());
+                    }
+                }
+                Some(_) => {}
+                None => return Err(FontError::InvalidPsf("truncated unicode table")),
+            }
+        }
+    }
+    Ok(font)
+}
+
+fn parse_psf2(data: &[u8]) -> Result<BitmapFont, FontError> {
+    let header = |index: usize| {
+        data.get(index * 4..index * 4 + 4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .ok_or(FontError::InvalidPsf("truncated header"))
+    };
+    let header_size = header(2)?;
+    let flags = header(3)?;
+    let count = header(4)?;
+    let glyph_size = header(5)?;
+    let height = header(6)?;
+    let width = header(7)?;
+    let width = u16::try_from(width)
+        .ok()
+        .filter(|&width| width <= 64)
+        .ok_or(FontError::GlyphTooWide(width))?;
+    if width == 0 {
+        return Err(FontError::InvalidPsf("invalid width"));
+    }
+    let height = u16::try_from(height)
+        .ok()
+        .filter(|&height| height > 0)
+        .ok_or(FontError::InvalidPsf("invalid height"))?;
+    let rest = data
+        .get(usize_from(header_size)?..)
+        .ok_or(FontError::InvalidPsf("truncated header"))?;
+    let glyphs = Glyphs::new(
+        rest,
+        usize_from(count)?,
+        width,
+        height,
+        usize_from(glyph_size)?,
+    )?;
+    let mut font = BitmapFont::new(width, height);
+    if flags & PSF2_HAS_TABLE == 0 {
+        glyphs.insert_by_index(&mut font);
+        return Ok(font);
+    }
+    let mut table = glyphs.rest;
+    for index in 0..glyphs.count {
+        let end = table
+            .iter()
+            .position(|&byte| byte == PSF2_SEPARATOR)
+            .ok_or(FontError::InvalidPsf("truncated unicode table"))?;
+        let entry = &table[..end];
+        table = &table[end + 1..];
+        // sequences of several code points that share a glyph are not supported
+        let singles = entry
+            .split(|&byte| byte == PSF2_START_SEQUENCE)
+            .next()
+            .unwrap_or_default();
+        let singles = std::str::from_utf8(singles)
+            .map_err(|_| FontError::InvalidPsf("invalid unicode table"))?;
+        let glyph = glyphs.get(index);
+        for c in singles.chars() {
+            font.insert(c, glyph.clone());
+        }
+    }
+    Ok(font)
+}
+
+/// The glyph bitmaps of a PSF font, followed by the rest of the data.
+struct Glyphs<'d> {
+    data: &'d [u8],
+    rest: &'d [u8],
+    count: usize,
+    width: u16,
+    height: u16,
+    glyph_size: usize,
+}
+
+impl<'d> Glyphs<'d> {
+    fn new(
+        data: &'d [u8],
+        count: usize,
+        width: u16,
+        height: u16,
+        glyph_size: usize,
+    ) -> Result<Self, FontError> {
+        let row_size = usize::from(width.div_ceil(8));
+        let bitmap_size = row_size
+            .checked_mul(usize::from(height))
+            .ok_or(FontError::InvalidPsf("size too large"))?;
+        if glyph_size < bitmap_size {
+            return Err(FontError::InvalidPsf(
+                "glyph size is too small for the width and height",
+            ));
+        }
+        let (data, rest) = count
+            .checked_mul(glyph_size)
+            .filter(|&len| len <= data.len())
+            .map(|len| data.split_at(len))
+            .ok_or(FontError::InvalidPsf("truncated glyph data"))?;
+        Ok(Self {
+            data,
+            rest,
+            count,
+            width,
+            height,
+            glyph_size,
+        })
+    }
+
+    fn get(&self, index: usize) -> Glyph {
+        let row_size = usize::from(self.width.div_ceil(8));
+        let bitmap = &self.data[index * self.glyph_size..][..self.glyph_size];
+        let rows = bitmap
+            .chunks_exact(row_size)
+            .take(usize::from(self.height))
+            .map(|row| glyph_row(row, self.width))
+            .collect::<Vec<_>>();
+        Glyph::new(self.width, rows)
+    }
+
+    /// Adds each glyph to the font as the character with the same code point as its index.
+    fn insert_by_index(&self, font: &mut BitmapFont) {
+        for index in 0..self.count {
+            let c = u32::try_from(index).ok().and_then(char::from_u32);
+            if let Some(c) = c {
+                font.insert(c, self.get(index));
+            }
+        }
+    }
+}
+
+fn usize_from(value: u32) -> Result<usize, FontError> {
+    usize::try_from(value).map_err(|_| FontError::InvalidPsf("size too large"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Font;
+
+    /// Builds a PSF2 font with 9 pixel wide glyphs that are 2 pixels tall.
+    fn psf2(glyphs: &[[u8; 4]], table: Option<&[u8]>) -> Vec<u8> {
+        let flags = u32::from(table.is_some());
+        let header = [0, 32, flags, u32::try_from(glyphs.len()).unwrap(), 4, 2, 9];
+        let mut data = PSF2_MAGIC.to_vec();
+        data.extend(header.iter().flat_map(|value| value.to_le_bytes()));
+        data.extend(glyphs.iter().flatten());
+        data.extend(table.unwrap_or_default());
+        data
+    }
+
+    #[test]
+    fn detect() {
+        assert!(is_psf(&[0x36, 0x04, 0, 8]));
+        assert!(is_psf(&psf2(&[], None)));
+        assert!(!is_psf(b"STARTFONT 2.1"));
+    }
+
+    #[test]
+    fn parse_psf1_without_table() {
+        let mut data = vec![0x36, 0x04, 0, 2];
+        data.extend((0..=255u8).flat_map(|index| [index, 0x80]));
+        let font = parse(&data).unwrap();
+        assert_eq!((font.width(), font.height()), (8, 2));
+        assert_eq!(font.len(), 256);
+        assert_eq!(font.glyph("A"), Some(Glyph::new(8, vec![0b1000_0010, 0b1])));
+    }
+
+    #[test]
+    fn parse_psf1_with_table() {
+        let mut data = vec![0x36, 0x04, 0x02, 1];
+        data.extend((0..=255u8).map(|index| index.reverse_bits()));
+        // glyph 0 is used for 'é' and 'e', glyph 1 for the sequence "e\u{301}", and the rest for
+        // nothing
+        let table = [0xE9, 0x65, 0xFFFF, 0xFFFE, 0x65, 0x301, 0xFFFF];
+        data.extend(table.iter().flat_map(|code: &u16| code.to_le_bytes()));
+        data.extend([0xFF; 254 * 2]);
+        let font = parse(&data).unwrap();
+        assert_eq!(font.len(), 2);
+        assert_eq!(font.glyph("é"), Some(Glyph::new(8, vec![0])));
+        assert_eq!(font.glyph("e"), Some(Glyph::new(8, vec![0])));
+    }
+
+    #[test]
+    fn parse_psf2_with_table() {
+        let glyphs = [[0x80, 0x80, 0xFF, 0x80], [0x00, 0x00, 0x00, 0x00]];
+        // glyph 0 is used for 'A' and 'a', and glyph 1 for 'Ж' and the sequence "x"
+        let table = [b"Aa\xFF".as_slice(), "Ж".as_bytes(), b"\xFEx\xFF"].concat();
+        let font = parse(&psf2(&glyphs, Some(&table))).unwrap();
+        assert_eq!((font.width(), font.height()), (9, 2));
+        let glyph = Glyph::new(9, vec![0b1_0000_0001, 0b1_1111_1111]);
+        assert_eq!(font.glyph("A"), Some(glyph.clone()));
+        assert_eq!(font.glyph("a"), Some(glyph));
+        assert_eq!(font.glyph("Ж"), Some(Glyph::new(9, vec![0, 0])));
+        assert_eq!(font.glyph("x"), None);
+    }
+
+    #[test]
+    fn truncated_glyphs() {
+        let mut data = psf2(&[[0; 4]; 2], None);
+        data.truncate(data.len() - 1);
+        assert!(matches!(
+            parse(&data),
+            Err(FontError::InvalidPsf("truncated glyph data"))
+        ));
+        assert!(matches!(
+            parse(&data[..10]),
+            Err(FontError::InvalidPsf("truncated header"))
+        ));
+    }
+
+    #[test]
+    fn empty_glyphs() {
+        let mut data = psf2(&[[0; 4]], None);
+        data[28..32].copy_from_slice(&0u32.to_le_bytes());
+        assert!(matches!(
+            parse(&data),
+            Err(FontError::InvalidPsf("invalid width"))
+        ));
+        let mut data = psf2(&[[0; 4]], None);
+        data[24..28].copy_from_slice(&0u32.to_le_bytes());
+        assert!(matches!(
+            parse(&data),
+            Err(FontError::InvalidPsf("invalid height"))
+        ));
+        assert!(matches!(
+            parse(&[0x36, 0x04, 0, 0]),
+            Err(FontError::InvalidPsf("invalid height"))
+        ));
+    }
+
+    #[test]
+    fn invalid_glyph_size() {
+        let mut data = psf2(&[[0; 4]], None);
+        data[20..24].copy_from_slice(&3u32.to_le_bytes());
+        assert!(matches!(
+            parse(&data),
+            Err(FontError::InvalidPsf(
+                "glyph size is too small for the width and height"
+            ))
+        ));
+        let mut data = psf2(&[[0; 4]], None);
+        data[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+        data[20..24].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            parse(&data),
+            Err(FontError::InvalidPsf("truncated glyph data"))
+        ));
+    }
+
+    #[test]
+    fn glyph_too_wide() {
+        let mut data = psf2(&[], None);
+        data[28..32].copy_from_slice(&65u32.to_le_bytes());
+        assert!(matches!(parse(&data), Err(FontError::GlyphTooWide(65))));
+    }
+}
@@ -0,0 +1,171 @@
+use std::ops::Range;
+
+use ratatui_core::layout::{Position, Rect};
+
+/// The position of a grapheme in the lines of a [`BigText`](crate::BigText).
+///
+/// Positions are ordered by line, and then by grapheme within the line. A position one past the
+/// last grapheme of a line is the end of that line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextPosition {
+    /// The index of the line in [`BigText::lines`](crate::BigText::lines).
+    pub line: usize,
+    /// The index of the grapheme in the line.
+    pub grapheme: usize,
+}
+
+impl TextPosition {
+    /// Creates a position from a line index and a grapheme index.
+    pub const fn new(line: usize, grapheme: usize) -> Self {
+        Self { line, grapheme }
+    }
+}
+
+/// The state of a [`BigText`](crate::BigText) that is rendered as an editable field.
+///
+/// The state holds a cursor and a selection, which are drawn by inverting the cells of the
+/// graphemes that they cover. A cursor at the end of a line is drawn as an inverted blank glyph
+/// after the line. The state also remembers where each grapheme was rendered, so that a mouse
+/// click can be mapped back to a position in the text with [`position_at`](Self::position_at).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::StatefulWidget;
+/// use tui_big_text::{BigText, BigTextState, TextPosition};
+///
+/// let big_text = BigText::builder().lines(vec!["Hello".into()]).build();
+/// let mut state = BigTextState::new();
+/// state.set_cursor(TextPosition::new(0, 5));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 60, 8));
+/// big_text.render(buf.area, &mut buf, &mut state);
+///
+/// // a click on the second glyph
+/// let position = state.position_at((10, 3));
+/// assert_eq!(position, Some(TextPosition::new(0, 1)));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct BigTextState {
+    cursor: Option<TextPosition>,
+    /// The end of the selection that stays put as the cursor moves.
+    anchor: Option<TextPosition>,
+    /// The graphemes that were rendered. Not set until the first render call.
+    pub(crate) hits: Vec<Hit>,
+}
+
+/// Where a grapheme was rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Hit {
+    pub(crate) cell: Rect,
+    pub(crate) position: TextPosition,
+}
+
+impl BigTextState {
+    /// Creates a state without a cursor or selection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The position of the cursor, if there is one.
+    pub const fn cursor(&self) -> Option<TextPosition> {
+        self.cursor
+    }
+
+    /// Moves the cursor to a position, clearing the selection.
+    ///
+    /// Positions past the end of a line are drawn at the end of the line.
+    pub fn set_cursor(&mut self, position: TextPosition) {
+        self.cursor = Some(position);
+        self.anchor = None;
+    }
+
+    /// Removes the cursor and the selection.
+    pub fn hide_cursor(&mut self) {
+        self.cursor = None;
+        self.anchor = None;
+    }
+
+    /// Moves the cursor to a position, selecting the text between it and where the cursor was.
+    ///
+    /// If there is already a selection, it is extended or shrunk from the same starting point,
+    /// like a shift-click.
+    pub fn select_to(&mut self, position: TextPosition) {
+        self.anchor = self.anchor.or(self.cursor).or(Some(position));
+        self.cursor = Some(position);
+    }
+
+    /// Clears the selection, leaving the cursor where it is.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// The selected graphemes, from the first selected grapheme up to but not including the last.
+    ///
+    /// Returns `None` if nothing is selected.
+    pub fn selection(&self) -> Option<Range<TextPosition>> {
+        let (anchor, cursor) = (self.anchor?, self.cursor?);
+        let selection = anchor.min(cursor)..anchor.max(cursor);
+        (!selection.is_empty()).then_some(selection)
+    }
+
+    /// The position in the text at a terminal cell, such as the column and row of a mouse click.
+    ///
+    /// This uses the layout of the last render. A cell within a grapheme maps to that grapheme, and
+    /// a cell before or after the graphemes of a row maps to the start or the end of the row.
+    /// Returns `None` if no graphemes were rendered in the row of the cell, such as for a click
+    /// below the text or on an empty line.
+    pub fn position_at(&self, position: impl Into<Position>) -> Option<TextPosition> {
+        let position = position.into();
+        let mut row = self
+            .hits
+            .iter()
+            .filter(|hit| (hit.cell.top()..hit.cell.bottom()).contains(&position.y))
+            .peekable();
+        let first = **row.peek()?;
+        if position.x < first.cell.left() {
+            return Some(first.position);
+        }
+        let mut last = first;
+        for hit in row {
+            if hit.cell.contains(position) {
+                return Some(hit.position);
+            }
+            last = *hit;
+        }
+        Some(TextPosition::new(
+            last.position.line,
+            last.position.grapheme + 1,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection() {
+        let mut state = BigTextState::new();
+        assert_eq!(state.selection(), None);
+        state.set_cursor(TextPosition::new(0, 3));
+        state.select_to(TextPosition::new(0, 1));
+        assert_eq!(
+            state.selection(),
+            Some(TextPosition::new(0, 1)..TextPosition::new(0, 3))
+        );
+        // the selection is extended from the same starting point
+        state.select_to(TextPosition::new(1, 0));
+        assert_eq!(
+            state.selection(),
+            Some(TextPosition::new(0, 3)..TextPosition::new(1, 0))
+        );
+        state.clear_selection();
+        assert_eq!(state.selection(), None);
+        assert_eq!(state.cursor(), Some(TextPosition::new(1, 0)));
+        state.hide_cursor();
+        assert_eq!(state.cursor(), None);
+    }
+}
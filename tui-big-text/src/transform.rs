@@ -0,0 +1,60 @@
+/// The rotation of the glyphs of [`BigText`](crate::BigText), clockwise.
+///
+/// Each glyph is rotated before it is mapped to cells with the [`PixelSize`](crate::PixelSize).
+/// A quarter turn rotates the lines along with their glyphs: turned clockwise, each line runs down
+/// the area and the lines follow each other from right to left, and turned anticlockwise, each
+/// line runs up the area and the lines follow each other from left to right. Glyphs that are
+/// turned upside down are rotated in place, so a line still runs from left to right.
+///
+/// Glyphs taller than 64 pixels are cut off at 64 pixels when they are rotated a quarter turn, as
+/// that is the widest that a [`Glyph`](crate::Glyph) can be.
+///
+/// # Example
+///
+/// Text that runs down the side of the screen, such as a sidebar banner:
+///
+/// ```rust
+/// use tui_big_text::{BigText, PixelSize, Rotation};
+///
+/// let big_text = BigText::builder()
+///     .pixel_size(PixelSize::Quadrant)
+///     .rotation(Rotation::Degrees90)
+///     .lines(vec!["SIDEBAR".into()])
+///     .build();
+/// assert_eq!(big_text.size().width, 4);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Rotation {
+    /// The glyphs are drawn upright.
+    #[default]
+    None,
+    /// The glyphs are rotated a quarter turn clockwise, so that their tops face right.
+    Degrees90,
+    /// The glyphs are drawn upside down.
+    Degrees180,
+    /// The glyphs are rotated three quarter turns clockwise, so that their tops face left.
+    Degrees270,
+}
+
+impl Rotation {
+    /// Returns `true` if the width and height of the glyphs are swapped by the rotation.
+    pub(crate) const fn is_quarter_turn(self) -> bool {
+        matches!(self, Self::Degrees90 | Self::Degrees270)
+    }
+}
+
+/// The mirroring of the glyphs of [`BigText`](crate::BigText).
+///
+/// Each glyph is mirrored in place after it is rotated, so the order of the glyphs in a line is
+/// unchanged. Reverse the graphemes of a line as well to mirror the whole line. Mirroring
+/// vertically draws a reflection of the text, for example below the text itself.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Mirror {
+    /// The glyphs are not mirrored.
+    #[default]
+    None,
+    /// The left and right of the glyphs are swapped.
+    Horizontal,
+    /// The top and bottom of the glyphs are swapped.
+    Vertical,
+}